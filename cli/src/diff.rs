@@ -0,0 +1,412 @@
+//  DIFF.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:20:37
+//  Last edited:
+//    19 Oct 2026, 01:46:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `diff`-subcommand, which compares the enums found by
+//!   [`discover()`](crate::discover::discover()) in two versions of a crate.
+//!
+//!   Enums are matched by their Rust path, or else by their type name or
+//!   identifier (in case they moved). Within matched enums, variants are
+//!   matched by name. Variants that disappeared are then heuristically
+//!   matched to variants that appeared to find renames, by (in order):
+//!   - the new variant having the old name as alias, or the old variant
+//!     having had the new name as alias;
+//!   - the Rust identifier of the variant being unchanged; and
+//!   - the variant being at the same index.
+//!
+//!   Changes are breaking if names that may have been persisted no longer
+//!   parse, i.e., if an enum is removed, its type name changes, a variant is
+//!   removed or renamed without keeping its old name as alias, or a variant
+//!   loses an alias. Reordering variants is only breaking if asked.
+//
+
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+
+use serde_json::{json, Value};
+
+use crate::discover::EnumInfo;
+
+
+/***** AUXILLARY *****/
+/// Why two variants with different names are assumed to be the same.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RenameReason {
+    /// The new variant has the old name as alias, so the old name still parses.
+    NewAliasesOld,
+    /// The old variant had the new name as alias, so the old name no longer parses.
+    OldAliasesNew,
+    /// The Rust identifier of the variant is unchanged.
+    Ident,
+    /// The variants are at the same index.
+    Index,
+}
+impl RenameReason {
+    /// Returns a short name for this reason, as used in JSON.
+    ///
+    /// # Returns
+    /// The name, in snake_case.
+    pub fn kind(&self) -> &'static str {
+        use RenameReason::*;
+        match self {
+            NewAliasesOld => "new_aliases_old",
+            OldAliasesNew => "old_aliases_new",
+            Ident => "ident",
+            Index => "index",
+        }
+    }
+}
+impl Display for RenameReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use RenameReason::*;
+        match self {
+            NewAliasesOld => write!(f, "old name kept as alias"),
+            OldAliasesNew => write!(f, "new name was an alias"),
+            Ident => write!(f, "same identifier"),
+            Index => write!(f, "same index"),
+        }
+    }
+}
+
+/// Describes one way in which an enum differs between two versions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// The enum only exists in the new version.
+    EnumAdded,
+    /// The enum only exists in the old version.
+    EnumRemoved,
+    /// The enum is defined in another module, but still matched by type name or identifier.
+    EnumMoved { old: String },
+    /// The type name changed, possibly because `#[enum_debug(name = "...")]` or
+    /// `#[enum_debug(path)]` changed.
+    TypeNameChanged { old: String, new: String, old_path: bool, new_path: bool },
+    /// A variant only exists in the new version.
+    VariantAdded { name: String, index: usize },
+    /// A variant only exists in the old version.
+    VariantRemoved { name: String, index: usize },
+    /// A variant has a different name in the new version.
+    VariantRenamed { old: String, new: String, reason: RenameReason },
+    /// An alias of a variant is no longer a name or alias of that variant in the new version.
+    AliasRemoved { name: String, alias: String },
+    /// A variant is ordered differently relative to the other variants.
+    VariantMoved { name: String, old: usize, new: usize },
+}
+impl Change {
+    /// Returns whether this change is breaking, i.e., may make persisted names unreadable.
+    ///
+    /// # Arguments
+    /// - `strict_order`: Whether to consider reordered variants breaking too (e.g., because
+    ///   variant indices are persisted).
+    ///
+    /// # Returns
+    /// True if the change is breaking, or false otherwise.
+    pub fn is_breaking(&self, strict_order: bool) -> bool {
+        use Change::*;
+        match self {
+            EnumAdded | EnumMoved { .. } | VariantAdded { .. } => false,
+            VariantRenamed { reason, .. } => *reason != RenameReason::NewAliasesOld,
+            EnumRemoved | TypeNameChanged { .. } | VariantRemoved { .. } | AliasRemoved { .. } => true,
+            VariantMoved { .. } => strict_order,
+        }
+    }
+
+    /// Returns a short name for the kind of this change, as used in JSON.
+    ///
+    /// # Returns
+    /// The name, in snake_case.
+    pub fn kind(&self) -> &'static str {
+        use Change::*;
+        match self {
+            EnumAdded => "enum_added",
+            EnumRemoved => "enum_removed",
+            EnumMoved { .. } => "enum_moved",
+            TypeNameChanged { .. } => "type_name_changed",
+            VariantAdded { .. } => "variant_added",
+            VariantRemoved { .. } => "variant_removed",
+            VariantRenamed { .. } => "variant_renamed",
+            AliasRemoved { .. } => "alias_removed",
+            VariantMoved { .. } => "variant_moved",
+        }
+    }
+}
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Change::*;
+        match self {
+            EnumAdded => write!(f, "enum added"),
+            EnumRemoved => write!(f, "enum removed"),
+            EnumMoved { old } => write!(f, "enum moved from '{old}'"),
+            TypeNameChanged { old, new, old_path, new_path } => {
+                write!(f, "type name changed from '{old}' to '{new}'")?;
+                match (old_path, new_path) {
+                    (false, true) => write!(f, " (now derived from the path)"),
+                    (true, false) => write!(f, " (no longer derived from the path)"),
+                    _ => Ok(()),
+                }
+            },
+            VariantAdded { name, index } => write!(f, "variant '{name}' added at index {index}"),
+            VariantRemoved { name, index } => write!(f, "variant '{name}' removed from index {index}"),
+            VariantRenamed { old, new, reason } => write!(f, "variant '{old}' renamed to '{new}' ({reason})"),
+            AliasRemoved { name, alias } => write!(f, "alias '{alias}' removed from variant '{name}'"),
+            VariantMoved { name, old, new } => write!(f, "variant '{name}' moved from index {old} to {new}"),
+        }
+    }
+}
+
+/// The changes to one enum between two versions.
+#[derive(Clone, Debug)]
+pub struct EnumDiff {
+    /// The Rust path of the enum (in the new version, if it exists there).
+    pub rust_path: String,
+    /// The type name of the enum (in the new version, if it exists there).
+    pub type_name: String,
+    /// The changes, which may be empty.
+    pub changes:   Vec<Change>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds which elements of a sequence are not in its longest increasing subsequence.
+///
+/// # Arguments
+/// - `seq`: The sequence to search.
+///
+/// # Returns
+/// For every element, whether it's out of order.
+fn out_of_order(seq: &[usize]) -> Vec<bool> {
+    // Simple O(n^2) DP, as enums don't have that many variants
+    let mut lengths: Vec<usize> = vec![1; seq.len()];
+    let mut prev: Vec<Option<usize>> = vec![None; seq.len()];
+    for i in 0..seq.len() {
+        for j in 0..i {
+            if seq[j] < seq[i] && lengths[j] + 1 > lengths[i] {
+                lengths[i] = lengths[j] + 1;
+                prev[i] = Some(j);
+            }
+        }
+    }
+
+    let mut res: Vec<bool> = vec![true; seq.len()];
+    let mut i: Option<usize> = (0..seq.len()).max_by_key(|i| (lengths[*i], std::cmp::Reverse(*i)));
+    while let Some(j) = i {
+        res[j] = false;
+        i = prev[j];
+    }
+    res
+}
+
+/// Compares the variants of two versions of an enum.
+///
+/// # Arguments
+/// - `old`: The old version.
+/// - `new`: The new version.
+/// - `changes`: The list to add the changes to.
+fn diff_variants(old: &EnumInfo, new: &EnumInfo, changes: &mut Vec<Change>) {
+    // Match the variants by name first
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut removed: Vec<usize> = Vec::new();
+    for (i, variant) in old.variants.iter().enumerate() {
+        match new.variants.iter().position(|other| other.name == variant.name) {
+            Some(j) => pairs.push((i, j)),
+            None => removed.push(i),
+        }
+    }
+    let mut added: Vec<usize> = (0..new.variants.len()).filter(|j| !pairs.iter().any(|(_, other)| other == j)).collect();
+
+    // Then find renames among the rest
+    let mut renames: Vec<(usize, usize, RenameReason)> = Vec::new();
+    for reason in [RenameReason::NewAliasesOld, RenameReason::OldAliasesNew, RenameReason::Ident, RenameReason::Index] {
+        removed.retain(|i| {
+            let pos: Option<usize> = added.iter().position(|j| match reason {
+                RenameReason::NewAliasesOld => new.variants[*j].aliases.contains(&old.variants[*i].name),
+                RenameReason::OldAliasesNew => old.variants[*i].aliases.contains(&new.variants[*j].name),
+                RenameReason::Ident => new.idents[*j] == old.idents[*i],
+                RenameReason::Index => *j == *i,
+            });
+            match pos {
+                Some(pos) => {
+                    renames.push((*i, added.remove(pos), reason));
+                    false
+                },
+                None => true,
+            }
+        });
+    }
+
+    // Report them
+    for i in removed {
+        changes.push(Change::VariantRemoved { name: old.variants[i].name.clone(), index: i });
+    }
+    renames.sort_by_key(|(i, _, _)| *i);
+    for (i, j, reason) in &renames {
+        changes.push(Change::VariantRenamed { old: old.variants[*i].name.clone(), new: new.variants[*j].name.clone(), reason: *reason });
+    }
+    for j in added {
+        changes.push(Change::VariantAdded { name: new.variants[j].name.clone(), index: j });
+    }
+
+    // Old aliases that the matched variant no longer answers to don't parse anymore
+    pairs.extend(renames.into_iter().map(|(i, j, _)| (i, j)));
+    pairs.sort();
+    for (i, j) in &pairs {
+        let (old, new) = (&old.variants[*i], &new.variants[*j]);
+        for alias in &old.aliases {
+            if new.name != *alias && !new.aliases.contains(alias) {
+                changes.push(Change::AliasRemoved { name: new.name.clone(), alias: alias.clone() });
+            }
+        }
+    }
+
+    // Finally, find the variants that aren't in the same relative order anymore
+    let moved: Vec<bool> = out_of_order(&pairs.iter().map(|(_, j)| *j).collect::<Vec<usize>>());
+    for ((i, j), moved) in pairs.into_iter().zip(moved) {
+        if moved {
+            changes.push(Change::VariantMoved { name: new.variants[j].name.clone(), old: i, new: j });
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Compares the enums in two versions of a crate.
+///
+/// # Arguments
+/// - `old`: The enums in the old version.
+/// - `new`: The enums in the new version.
+///
+/// # Returns
+/// An [`EnumDiff`] for every enum in either version, in the order of the new version followed by
+/// the enums that were removed.
+pub fn diff(old: &[EnumInfo], new: &[EnumInfo]) -> Vec<EnumDiff> {
+    // Match the enums by path first, and then by type name or identifier if they moved
+    let mut matches: Vec<Option<usize>> = new.iter().map(|info| old.iter().position(|other| other.rust_path() == info.rust_path())).collect();
+    let keys: [fn(&EnumInfo) -> String; 2] = [|info| info.type_name.clone(), |info| info.ident.clone()];
+    for key in keys {
+        for (j, info) in new.iter().enumerate() {
+            if matches[j].is_some() {
+                continue;
+            }
+            let unmatched = |i: &usize| !matches.contains(&Some(*i));
+            let candidates: Vec<usize> = (0..old.len()).filter(|i| unmatched(i) && key(&old[*i]) == key(info)).collect();
+            if candidates.len() == 1 {
+                matches[j] = Some(candidates[0]);
+            }
+        }
+    }
+
+    // Compare the matched enums
+    let mut res: Vec<EnumDiff> = Vec::with_capacity(new.len());
+    for (info, i) in new.iter().zip(&matches) {
+        let mut changes: Vec<Change> = Vec::new();
+        match i {
+            Some(i) => {
+                let old: &EnumInfo = &old[*i];
+                if old.rust_path() != info.rust_path() {
+                    changes.push(Change::EnumMoved { old: old.rust_path() });
+                }
+                if old.type_name != info.type_name {
+                    changes.push(Change::TypeNameChanged {
+                        old:      old.type_name.clone(),
+                        new:      info.type_name.clone(),
+                        old_path: old.path,
+                        new_path: info.path,
+                    });
+                }
+                diff_variants(old, info, &mut changes);
+            },
+            None => changes.push(Change::EnumAdded),
+        }
+        res.push(EnumDiff { rust_path: info.rust_path(), type_name: info.type_name.clone(), changes });
+    }
+    for (i, info) in old.iter().enumerate() {
+        if !matches.contains(&Some(i)) {
+            res.push(EnumDiff { rust_path: info.rust_path(), type_name: info.type_name.clone(), changes: vec![Change::EnumRemoved] });
+        }
+    }
+    res
+}
+
+
+
+/// Renders the differences between enums as human-readable text.
+///
+/// Only enums with changes are listed, followed by a summary line.
+///
+/// # Arguments
+/// - `diffs`: The differences to render.
+/// - `strict_order`: Whether reordered variants are breaking (see [`Change::is_breaking()`]).
+///
+/// # Returns
+/// The rendered text.
+pub fn text(diffs: &[EnumDiff], strict_order: bool) -> String {
+    let mut res: String = String::new();
+    let mut breaking: usize = 0;
+    for diff in diffs.iter().filter(|diff| !diff.changes.is_empty()) {
+        writeln!(res, "{} ({}):", diff.rust_path, diff.type_name).unwrap();
+        for change in &diff.changes {
+            write!(res, "  - {change}").unwrap();
+            if change.is_breaking(strict_order) {
+                res.push_str(" [breaking]");
+                breaking += 1;
+            }
+            res.push('\n');
+        }
+    }
+    match breaking {
+        0 if res.is_empty() => writeln!(res, "No changes in {} enum(s)", diffs.len()).unwrap(),
+        0 => writeln!(res, "No breaking changes").unwrap(),
+        n => writeln!(res, "{n} breaking change(s)").unwrap(),
+    }
+    res
+}
+
+/// Renders the differences between enums as JSON.
+///
+/// # Arguments
+/// - `diffs`: The differences to render.
+/// - `strict_order`: Whether reordered variants are breaking (see [`Change::is_breaking()`]).
+///
+/// # Returns
+/// An array with an object per enum that has changes, each with a list of changes.
+pub fn json(diffs: &[EnumDiff], strict_order: bool) -> Value {
+    Value::Array(
+        diffs
+            .iter()
+            .filter(|diff| !diff.changes.is_empty())
+            .map(|diff| {
+                json!({
+                    "rust_path": diff.rust_path,
+                    "type_name": diff.type_name,
+                    "changes": diff.changes.iter().map(|change| {
+                        let mut res: Value = match change {
+                            Change::EnumAdded | Change::EnumRemoved => json!({}),
+                            Change::EnumMoved { old } => json!({ "old": old }),
+                            Change::TypeNameChanged { old, new, old_path, new_path } => {
+                                json!({ "old": old, "new": new, "old_path": old_path, "new_path": new_path })
+                            },
+                            Change::VariantAdded { name, index } | Change::VariantRemoved { name, index } => json!({ "name": name, "index": index }),
+                            Change::VariantRenamed { old, new, reason } => json!({ "old": old, "new": new, "reason": reason.kind() }),
+                            Change::AliasRemoved { name, alias } => json!({ "name": name, "alias": alias }),
+                            Change::VariantMoved { name, old, new } => json!({ "name": name, "old": old, "new": new }),
+                        };
+                        res["kind"] = json!(change.kind());
+                        res["breaking"] = json!(change.is_breaking(strict_order));
+                        res
+                    }).collect::<Vec<Value>>(),
+                })
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,280 @@
+//  DISCOVER.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:11:39
+//  Last edited:
+//    19 Oct 2026, 00:21:55
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Finds the enums deriving `EnumDebug` in the sources of a crate.
+//!
+//!   Every `.rs`-file in the crate's `src/`-directory is parsed, and the
+//!   attributes of every enum deriving `EnumDebug` are interpreted by the same
+//!   code as the derive macro itself. Note that modules are assumed to live
+//!   in the files matching their names (i.e., `#[path = "..."]` is not
+//!   followed), and that `#[cfg(...)]` is ignored.
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Attribute, Item, ItemEnum, Path as SynPath};
+
+use crate::parse::{container_options, variant_infos, ContainerOptions, VariantInfo};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when discovering enums.
+#[derive(Debug)]
+pub enum DiscoverError {
+    /// Failed to read the manifest of the crate.
+    ManifestRead { path: PathBuf, err: std::io::Error },
+    /// Failed to parse the manifest of the crate.
+    ManifestParse { path: PathBuf, err: toml::de::Error },
+    /// Failed to read a directory.
+    DirRead { path: PathBuf, err: std::io::Error },
+    /// Failed to read a source file.
+    FileRead { path: PathBuf, err: std::io::Error },
+    /// Failed to parse a source file.
+    FileParse { path: PathBuf, err: syn::Error },
+    /// The attributes of an enum are not accepted by the derive macro.
+    IllegalAttributes { path: PathBuf, ident: String, err: syn::Error },
+}
+impl Display for DiscoverError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use DiscoverError::*;
+        match self {
+            ManifestRead { path, .. } => write!(f, "Failed to read manifest '{}'", path.display()),
+            ManifestParse { path, .. } => write!(f, "Failed to parse manifest '{}'", path.display()),
+            DirRead { path, .. } => write!(f, "Failed to read directory '{}'", path.display()),
+            FileRead { path, .. } => write!(f, "Failed to read source file '{}'", path.display()),
+            FileParse { path, .. } => write!(f, "Failed to parse source file '{}'", path.display()),
+            IllegalAttributes { path, ident, .. } => write!(f, "Illegal attributes on enum {ident} in '{}'", path.display()),
+        }
+    }
+}
+impl Error for DiscoverError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use DiscoverError::*;
+        match self {
+            ManifestRead { err, .. } => Some(err),
+            ManifestParse { err, .. } => Some(err),
+            DirRead { err, .. } => Some(err),
+            FileRead { err, .. } => Some(err),
+            FileParse { err, .. } => Some(err),
+            IllegalAttributes { err, .. } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// An enum deriving `EnumDebug` found in the sources of a crate.
+pub struct EnumInfo {
+    /// The file in which the enum is defined, relative to the root of the crate.
+    pub file:      PathBuf,
+    /// The path of the module in which the enum is defined, starting with the name of the crate.
+    pub module:    Vec<String>,
+    /// The identifier of the enum.
+    pub ident:     String,
+    /// The type name that `EnumDebug::type_name()` will return.
+    ///
+    /// If `path` is `true`, this is derived from the module path and may differ from the actual
+    /// name (e.g., for generic enums, or if `#[path = "..."]` is used).
+    pub type_name: String,
+    /// Whether `#[enum_debug(path)]` is given, i.e., the type name is that of
+    /// `std::any::type_name()`.
+    pub path:      bool,
+    /// The variants of the enum, in declaration order.
+    pub variants:  Vec<VariantInfo>,
+    /// The identifiers of the variants, in the same order as `variants`.
+    pub idents:    Vec<String>,
+}
+impl EnumInfo {
+    /// Returns the full path of the enum in Rust, e.g., `my_crate::jedi::Jedi`.
+    ///
+    /// This identifies the enum independently of its type name.
+    ///
+    /// # Returns
+    /// The module path and the identifier, joined by `::`.
+    #[inline]
+    pub fn rust_path(&self) -> String { format!("{}::{}", self.module.join("::"), self.ident) }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds the name of a crate from its manifest.
+///
+/// # Arguments
+/// - `root`: The root directory of the crate.
+///
+/// # Returns
+/// The name of the library target if given, or else the name of the package with dashes replaced
+/// by underscores. If there is no manifest, the name of `root` is used instead.
+///
+/// # Errors
+/// This function errors if the manifest exists but could not be read or parsed.
+fn crate_name(root: &Path) -> Result<String, DiscoverError> {
+    let path: PathBuf = root.join("Cargo.toml");
+    let manifest: String = match fs::read_to_string(&path) {
+        Ok(manifest) => manifest,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            let name: String = root.canonicalize().ok().and_then(|root| root.file_name().map(|name| name.to_string_lossy().into_owned())).unwrap_or_default();
+            return Ok(name.replace('-', "_"));
+        },
+        Err(err) => return Err(DiscoverError::ManifestRead { path, err }),
+    };
+    let manifest: toml::Table = manifest.parse().map_err(|err| DiscoverError::ManifestParse { path, err })?;
+    let name = |table: &str| manifest.get(table).and_then(|table| table.get("name")).and_then(toml::Value::as_str).map(String::from);
+    Ok(name("lib").or_else(|| name("package")).unwrap_or_default().replace('-', "_"))
+}
+
+/// Computes the path of the module defined by a source file.
+///
+/// # Arguments
+/// - `crate_name`: The name of the crate.
+/// - `file`: The path of the source file relative to the `src/`-directory.
+///
+/// # Returns
+/// The module path, starting with the name of the crate. Files in `src/bin` are treated as the
+/// crates of binaries.
+fn module_path(crate_name: &str, file: &Path) -> Vec<String> {
+    let mut parts: Vec<String> = file.with_extension("").iter().map(|part| part.to_string_lossy().into_owned()).collect();
+    let mut res: Vec<String> = if parts.len() > 1 && parts[0] == "bin" {
+        parts.remove(0);
+        vec![parts.remove(0).replace('-', "_")]
+    } else {
+        vec![crate_name.into()]
+    };
+    if parts.last().is_some_and(|part| part == "mod") || (parts.len() == 1 && (parts[0] == "lib" || parts[0] == "main")) {
+        parts.pop();
+    }
+    res.extend(parts);
+    res
+}
+
+/// Checks whether an item derives `EnumDebug`.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the item.
+///
+/// # Returns
+/// True if any `#[derive(...)]` lists a path ending in `EnumDebug`.
+fn derives_enum_debug(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("derive")
+            && attr
+                .parse_args_with(Punctuated::<SynPath, Comma>::parse_terminated)
+                .is_ok_and(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == "EnumDebug")))
+    })
+}
+
+/// Collects the source files in a directory, recursively.
+///
+/// # Arguments
+/// - `dir`: The directory to search.
+/// - `files`: The list to add the paths of the files to.
+///
+/// # Errors
+/// This function errors if any directory could not be read.
+fn source_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), DiscoverError> {
+    let entries = fs::read_dir(dir).map_err(|err| DiscoverError::DirRead { path: dir.into(), err })?;
+    for entry in entries {
+        let path: PathBuf = entry.map_err(|err| DiscoverError::DirRead { path: dir.into(), err })?.path();
+        if path.is_dir() {
+            source_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Finds the enums deriving `EnumDebug` in a list of items, recursing into inline modules.
+///
+/// # Arguments
+/// - `file`: The path of the file containing the items, relative to the root of the crate.
+/// - `module`: The path of the module containing the items.
+/// - `items`: The items to search.
+/// - `enums`: The list to add the found enums to.
+///
+/// # Errors
+/// This function errors if the attributes of an enum are illegal.
+fn find_enums(file: &Path, module: &mut Vec<String>, items: &[Item], enums: &mut Vec<EnumInfo>) -> Result<(), DiscoverError> {
+    for item in items {
+        match item {
+            Item::Enum(ItemEnum { attrs, ident, variants, .. }) if derives_enum_debug(attrs) => {
+                let illegal = |err: syn::Error| DiscoverError::IllegalAttributes { path: file.into(), ident: ident.to_string(), err };
+                let ContainerOptions { type_name, follow_serde, .. } = container_options(ident, attrs).map_err(illegal)?;
+                let idents: Vec<String> = variants.iter().map(|variant| variant.ident.to_string()).collect();
+                let variants: Vec<VariantInfo> = variant_infos(attrs, variants, follow_serde).map_err(illegal)?;
+                enums.push(EnumInfo {
+                    file: file.into(),
+                    module: module.clone(),
+                    ident: ident.to_string(),
+                    path: type_name.is_none(),
+                    type_name: type_name.unwrap_or_else(|| format!("{}::{ident}", module.join("::"))),
+                    variants,
+                    idents,
+                });
+            },
+            Item::Mod(item) => {
+                if let Some((_, items)) = &item.content {
+                    module.push(item.ident.to_string());
+                    find_enums(file, module, items, enums)?;
+                    module.pop();
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Finds the enums deriving `EnumDebug` in the sources of a crate.
+///
+/// # Arguments
+/// - `root`: The root directory of the crate, i.e., the one containing `Cargo.toml` and `src/`.
+///
+/// # Returns
+/// The enums found, ordered by file and then by their position in the file.
+///
+/// # Errors
+/// This function errors if the sources could not be read or parsed, or if the attributes of an
+/// enum would be rejected by the derive macro.
+pub fn discover(root: &Path) -> Result<Vec<EnumInfo>, DiscoverError> {
+    let crate_name: String = crate_name(root)?;
+    let src: PathBuf = root.join("src");
+    let mut files: Vec<PathBuf> = Vec::new();
+    source_files(&src, &mut files)?;
+    files.sort();
+
+    let mut enums: Vec<EnumInfo> = Vec::new();
+    for path in files {
+        let code: String = fs::read_to_string(&path).map_err(|err| DiscoverError::FileRead { path: path.clone(), err })?;
+        let file: syn::File = syn::parse_file(&code).map_err(|err| DiscoverError::FileParse { path: path.clone(), err })?;
+
+        let rel: &Path = path.strip_prefix(root).unwrap_or(&path);
+        let mut module: Vec<String> = module_path(&crate_name, path.strip_prefix(&src).unwrap_or(&path));
+        find_enums(rel, &mut module, &file.items, &mut enums)?;
+    }
+    Ok(enums)
+}
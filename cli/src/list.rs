@@ -0,0 +1,86 @@
+//  LIST.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:11:39
+//  Last edited:
+//    19 Oct 2026, 00:11:39
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the `list`-subcommand, which prints the enums found by
+//!   [`discover()`](crate::discover::discover()).
+//
+
+use std::fmt::Write as _;
+
+use serde_json::{json, Value};
+
+use crate::discover::EnumInfo;
+
+
+/***** LIBRARY *****/
+/// Renders enums as human-readable text.
+///
+/// Every enum is listed with the file it is defined in and its type name, followed by its
+/// variant names (and their aliases) on indented lines.
+///
+/// # Arguments
+/// - `enums`: The enums to render.
+///
+/// # Returns
+/// The rendered text.
+pub fn text(enums: &[EnumInfo]) -> String {
+    let mut res: String = String::new();
+    for info in enums {
+        write!(res, "{}: {}", info.file.display(), info.type_name).unwrap();
+        if info.path {
+            res.push_str(" (path)");
+        }
+        res.push('\n');
+        for variant in &info.variants {
+            write!(res, "  - {}", variant.name).unwrap();
+            if !variant.aliases.is_empty() {
+                write!(res, " (alias: {})", variant.aliases.join(", ")).unwrap();
+            }
+            res.push('\n');
+        }
+    }
+    res
+}
+
+/// Renders enums as JSON.
+///
+/// # Arguments
+/// - `enums`: The enums to render.
+///
+/// # Returns
+/// An array with an object per enum. Deprecation of variants is given as `false`, `true` or the
+/// reason given.
+pub fn json(enums: &[EnumInfo]) -> Value {
+    Value::Array(
+        enums
+            .iter()
+            .map(|info| {
+                json!({
+                    "file": info.file.to_string_lossy().replace('\\', "/"),
+                    "rust_path": info.rust_path(),
+                    "type_name": info.type_name,
+                    "path": info.path,
+                    "variants": info.variants.iter().map(|variant| json!({
+                        "name": variant.name,
+                        "aliases": variant.aliases,
+                        "hidden": variant.hidden,
+                        "deprecated": match &variant.deprecated {
+                            Some(Some(reason)) => json!(reason),
+                            Some(None) => json!(true),
+                            None => json!(false),
+                        },
+                        "description": variant.description,
+                    })).collect::<Vec<Value>>(),
+                })
+            })
+            .collect(),
+    )
+}
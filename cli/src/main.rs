@@ -0,0 +1,165 @@
+//  MAIN.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:11:39
+//  Last edited:
+//    19 Oct 2026, 00:21:55
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Entrypoint to the `enum-debug` binary, which inspects the enums deriving
+//!   `EnumDebug` in the sources of a crate without compiling it.
+//!
+//!   For example, to list the enums in the crate in the current directory:
+//!   ```sh
+//!   enum-debug list
+//!   ```
+//!   or to check that a new version does not break names persisted by an old
+//!   one:
+//!   ```sh
+//!   enum-debug diff path/to/old path/to/new
+//!   ```
+//!
+//!   The binary exits with status 1 if `diff` finds breaking changes, and with
+//!   status 2 on errors.
+//
+
+// Declare modules
+mod diff;
+mod discover;
+mod list;
+// NOTE: Shared with the derive macro, so the names found here are exactly the ones it derives
+#[allow(dead_code)]
+#[path = "../../derive/src/parse.rs"]
+mod parse;
+
+// Imports
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::diff::{diff, EnumDiff};
+use crate::discover::{discover, EnumInfo};
+
+
+/***** CONSTANTS *****/
+/// The status with which the binary exits if `diff` finds breaking changes.
+const EXIT_BREAKING: u8 = 1;
+/// The status with which the binary exits on errors.
+const EXIT_ERROR: u8 = 2;
+
+
+/***** ARGUMENTS *****/
+/// Inspects the enums deriving `EnumDebug` in the sources of a crate.
+#[derive(Parser)]
+#[command(name = "enum-debug", version, about)]
+struct Arguments {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The subcommands of the binary.
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the enums deriving `EnumDebug`, with their type names and variant names.
+    List {
+        /// The root directory of the crate to inspect (i.e., the one containing `src/`).
+        #[arg(default_value = ".")]
+        path:   PathBuf,
+        /// The format to print the enums in.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Compares the enums deriving `EnumDebug` in two versions of a crate, exiting with status 1
+    /// on breaking changes.
+    Diff {
+        /// The root directory of the old version of the crate.
+        old:          PathBuf,
+        /// The root directory of the new version of the crate.
+        new:          PathBuf,
+        /// The format to print the changes in.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format:       Format,
+        /// Also consider reordering variants a breaking change (e.g., if variant indices are
+        /// persisted).
+        #[arg(long)]
+        strict_order: bool,
+    },
+}
+
+/// The formats in which results can be printed.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human-readable text.
+    Text,
+    /// JSON, for other tools to process.
+    Json,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Prints an error and its sources to stderr.
+///
+/// # Arguments
+/// - `err`: The error to print.
+fn print_error(err: &dyn Error) {
+    eprintln!("error: {err}");
+    let mut source: Option<&dyn Error> = err.source();
+    while let Some(err) = source {
+        eprintln!("  caused by: {err}");
+        source = err.source();
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() -> ExitCode {
+    let args: Arguments = Arguments::parse();
+    match args.command {
+        Command::List { path, format } => {
+            let enums: Vec<EnumInfo> = match discover(&path) {
+                Ok(enums) => enums,
+                Err(err) => {
+                    print_error(&err);
+                    return ExitCode::from(EXIT_ERROR);
+                },
+            };
+            match format {
+                Format::Text => print!("{}", list::text(&enums)),
+                Format::Json => println!("{:#}", list::json(&enums)),
+            }
+            ExitCode::SUCCESS
+        },
+
+        Command::Diff { old, new, format, strict_order } => {
+            let (old, new): (Vec<EnumInfo>, Vec<EnumInfo>) = match discover(&old).and_then(|old| Ok((old, discover(&new)?))) {
+                Ok(res) => res,
+                Err(err) => {
+                    print_error(&err);
+                    return ExitCode::from(EXIT_ERROR);
+                },
+            };
+            let diffs: Vec<EnumDiff> = diff(&old, &new);
+            match format {
+                Format::Text => print!("{}", diff::text(&diffs, strict_order)),
+                Format::Json => println!("{:#}", diff::json(&diffs, strict_order)),
+            }
+            if diffs.iter().any(|diff| diff.changes.iter().any(|change| change.is_breaking(strict_order))) {
+                ExitCode::from(EXIT_BREAKING)
+            } else {
+                ExitCode::SUCCESS
+            }
+        },
+    }
+}
@@ -0,0 +1,122 @@
+//  DIFF.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:20:37
+//  Last edited:
+//    19 Oct 2026, 01:46:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Runs the `diff`-subcommand of the `enum-debug` binary on the crates in
+//!   `tests/fixtures/jedi` and `tests/fixtures/jedi-v2`.
+//
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Runs the `enum-debug` binary.
+///
+/// # Arguments
+/// - `args`: The arguments to give it.
+///
+/// # Returns
+/// Its [`Output`].
+fn enum_debug(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_enum-debug"))
+        .args(args)
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures"))
+        .output()
+        .unwrap()
+}
+
+
+
+
+
+/***** TESTS *****/
+#[test]
+fn diff_unchanged() {
+    let output: Output = enum_debug(&["diff", "jedi", "jedi"]);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "No changes in 4 enum(s)\n");
+}
+
+#[test]
+fn diff_text() {
+    let output: Output = enum_debug(&["diff", "jedi", "jedi-v2"]);
+    assert_eq!(output.status.code(), Some(1), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "jedi_archives::Droid (Droid):
+  - enum added
+jedi_archives::Jedi (Jedi):
+  - variant 'MaceWindu' removed from index 2 [breaking]
+  - variant 'AnakinSkywalker' renamed to 'DarthVader' (old name kept as alias)
+  - variant 'Yoda' added at index 1
+  - alias 'Vader' removed from variant 'DarthVader' [breaking]
+jedi_archives::Homeworld (World):
+  - type name changed from 'Planet' to 'World' [breaking]
+  - variant 'coruscant' renamed to 'capital' (same identifier) [breaking]
+jedi_archives::dark::Sith (jedi_archives::dark::Sith):
+  - enum moved from 'jedi_archives::sith::Sith'
+  - type name changed from 'jedi_archives::sith::Sith' to 'jedi_archives::dark::Sith' [breaking]
+jedi_archives::order::Rank (Rank):
+  - variant 'Master' moved from index 3 to 2
+5 breaking change(s)
+"
+    );
+}
+
+#[test]
+fn diff_json() {
+    let output: Output = enum_debug(&["diff", "--format", "json", "--strict-order", "jedi", "jedi-v2"]);
+    assert_eq!(output.status.code(), Some(1), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 5);
+    assert_eq!(
+        json[4],
+        serde_json::json!({
+            "rust_path": "jedi_archives::order::Rank",
+            "type_name": "Rank",
+            "changes": [{ "kind": "variant_moved", "name": "Master", "old": 3, "new": 2, "breaking": true }],
+        })
+    );
+}
+
+#[test]
+fn diff_removed_alias() {
+    // `AnakinSkywalker` answered to `Vader`, but `DarthVader` only keeps `AnakinSkywalker` as alias
+    let output: Output = enum_debug(&["diff", "--format", "json", "jedi", "jedi-v2"]);
+    assert_eq!(output.status.code(), Some(1), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[1]["rust_path"], "jedi_archives::Jedi");
+    assert!(
+        json[1]["changes"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!({ "kind": "alias_removed", "name": "DarthVader", "alias": "Vader", "breaking": true })),
+        "{json:#}"
+    );
+}
+
+#[test]
+fn diff_reverse() {
+    // Going back removes Droid, but reordering Rank again is only breaking with `--strict-order`
+    let output: Output = enum_debug(&["diff", "jedi-v2", "jedi"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("jedi_archives::Droid (Droid):\n  - enum removed [breaking]\n"), "{stdout}");
+    assert!(stdout.contains("  - variant 'DarthVader' renamed to 'AnakinSkywalker' (new name was an alias) [breaking]\n"), "{stdout}");
+    assert!(stdout.contains("jedi_archives::order::Rank (Rank):\n  - variant 'Knight' moved from index 3 to 2\n"), "{stdout}");
+}
+
+#[test]
+fn diff_missing() {
+    let output: Output = enum_debug(&["diff", "jedi", "nonexistent"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: Failed to read directory"));
+}
@@ -0,0 +1,41 @@
+use enum_debug::EnumDebug;
+use serde::Serialize;
+
+pub mod order;
+
+/// Now an `EnumDebug`.
+#[derive(Debug, EnumDebug)]
+pub enum Droid {
+    R2D2,
+    C3PO,
+}
+
+#[derive(EnumDebug)]
+pub enum Jedi {
+    ObiWanKenobi,
+    Yoda,
+    #[enum_debug(alias = "AnakinSkywalker")]
+    DarthVader,
+}
+
+#[derive(enum_debug::EnumDebug, Serialize)]
+#[enum_debug(name = "World", follow_serde)]
+#[serde(rename_all = "snake_case")]
+pub enum Homeworld {
+    Tatooine,
+    /// Where the Jedi Temple is.
+    #[serde(rename = "capital")]
+    Coruscant,
+    #[serde(rename = "dagobah_system")]
+    #[enum_debug(deprecated = "Only Yoda lives there")]
+    Dagobah,
+}
+
+pub mod dark {
+    #[derive(enum_debug::EnumDebug)]
+    #[enum_debug(path)]
+    pub enum Sith {
+        Sidious,
+        Vader(String),
+    }
+}
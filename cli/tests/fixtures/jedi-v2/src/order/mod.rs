@@ -0,0 +1,9 @@
+use enum_debug::EnumDebug;
+
+#[derive(Clone, Copy, EnumDebug)]
+pub enum Rank {
+    Youngling,
+    Padawan,
+    Master,
+    Knight,
+}
@@ -0,0 +1,41 @@
+use enum_debug::EnumDebug;
+use serde::Serialize;
+
+pub mod order;
+
+/// Not an `EnumDebug`, so not listed.
+#[derive(Debug)]
+pub enum Droid {
+    R2D2,
+    C3PO,
+}
+
+#[derive(EnumDebug)]
+pub enum Jedi {
+    ObiWanKenobi,
+    #[enum_debug(alias = "Vader")]
+    AnakinSkywalker,
+    #[enum_debug(hidden)]
+    MaceWindu,
+}
+
+#[derive(enum_debug::EnumDebug, Serialize)]
+#[enum_debug(name = "Planet", follow_serde)]
+#[serde(rename_all = "snake_case")]
+pub enum Homeworld {
+    Tatooine,
+    /// Where the Jedi Temple is.
+    Coruscant,
+    #[serde(rename = "dagobah_system")]
+    #[enum_debug(deprecated = "Only Yoda lives there")]
+    Dagobah,
+}
+
+pub mod sith {
+    #[derive(enum_debug::EnumDebug)]
+    #[enum_debug(path)]
+    pub enum Sith {
+        Sidious,
+        Vader(String),
+    }
+}
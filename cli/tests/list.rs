@@ -0,0 +1,95 @@
+//  LIST.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:11:39
+//  Last edited:
+//    19 Oct 2026, 00:11:39
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Runs the `list`-subcommand of the `enum-debug` binary on the crate in
+//!   `tests/fixtures/jedi`.
+//
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Runs the `enum-debug` binary.
+///
+/// # Arguments
+/// - `args`: The arguments to give it.
+///
+/// # Returns
+/// Its [`Output`].
+fn enum_debug(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_enum-debug"))
+        .args(args)
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures"))
+        .output()
+        .unwrap()
+}
+
+
+
+
+
+/***** TESTS *****/
+#[test]
+fn list_text() {
+    let output: Output = enum_debug(&["list", "jedi"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "src/lib.rs: Jedi
+  - ObiWanKenobi
+  - AnakinSkywalker (alias: Vader)
+  - MaceWindu
+src/lib.rs: Planet
+  - tatooine
+  - coruscant
+  - dagobah_system
+src/lib.rs: jedi_archives::sith::Sith (path)
+  - Sidious
+  - Vader
+src/order/mod.rs: Rank
+  - Youngling
+  - Padawan
+  - Knight
+  - Master
+"
+    );
+}
+
+#[test]
+fn list_json() {
+    let output: Output = enum_debug(&["list", "--format", "json", "jedi"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 4);
+    assert_eq!(
+        json[1],
+        serde_json::json!({
+            "file": "src/lib.rs",
+            "rust_path": "jedi_archives::Homeworld",
+            "type_name": "Planet",
+            "path": false,
+            "variants": [
+                { "name": "tatooine", "aliases": [], "hidden": false, "deprecated": false, "description": null },
+                { "name": "coruscant", "aliases": [], "hidden": false, "deprecated": false, "description": "Where the Jedi Temple is." },
+                { "name": "dagobah_system", "aliases": [], "hidden": false, "deprecated": "Only Yoda lives there", "description": null },
+            ],
+        })
+    );
+    assert_eq!(json[3]["rust_path"], "jedi_archives::order::Rank");
+}
+
+#[test]
+fn list_missing() {
+    let output: Output = enum_debug(&["list", "nonexistent"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: Failed to read directory"));
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    10 Dec 2022, 11:57:28
 //  Last edited:
-//    08 Oct 2024, 15:13:58
+//    19 Oct 2026, 01:45:32
 //  Auto updated?
 //    Yes
 //
@@ -12,12 +12,17 @@
 //!   Implements `#[derive(EnumDebug)]` for the `enum-debug` crate.
 //
 
+// Declare modules
+mod parse;
+
+// Imports
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
-use syn::punctuated::Punctuated;
+use quote::quote;
+use syn::ext::IdentExt as _;
 use syn::spanned::Spanned as _;
-use syn::token::Comma;
-use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Ident, Lit, Meta};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitByteStr};
+
+use crate::parse::{container_options, convert_case, doc_summary, is_identifier, variant_infos, ContainerOptions, VariantInfo};
 
 
 /***** HELPER MACROS *****/
@@ -41,86 +46,511 @@ pub fn derive_enum_debug(input: TokenStream) -> TokenStream {
     // Match what we're parsing
     match data {
         Data::Enum(e) => {
-            // Create the default name
-            let name: String = ident.to_string();
-            let mut name = quote!(#name).into();
-
-            // Find if we also have to derive the thing
-            for attr in attrs {
-                // Only do our own
-                if !attr.path().is_ident("enum_debug") {
-                    continue;
-                }
-
-                // Attempt to parse the list
-                let metas: Punctuated<Meta, Comma> = match attr.parse_args_with(Punctuated::parse_terminated) {
-                    Ok(metas) => metas,
-                    // Not for us
-                    Err(err) => {
-                        return err!(err.span(), "Failed to parse `enum_debug(...)` arguments as valid attributes");
-                    },
+            // Parse the attributes on the enum
+            let ContainerOptions { type_name, follow_serde, value_enum, json_schema, graphql, pyo3, rusqlite, sqlx, log, c_api } =
+                match container_options(&ident, &attrs) {
+                    Ok(opts) => opts,
+                    Err(err) => return err.into_compile_error().into(),
                 };
+            // NOTE: Only known at compile time if it's not the path
+            let const_name: Option<String> = type_name;
+            let name = match &const_name {
+                Some(name) => quote!(#name),
+                None => quote!(::std::any::type_name::<Self>()),
+            };
 
-                // Parse the attributes
-                for meta in metas {
-                    match meta {
-                        Meta::Path(path) => {
-                            if path.is_ident("path") {
-                                // Override with the path
-                                name = quote!(::std::any::type_name::<Self>());
-                            // NOTE: Legacy here, path used to be the default but now `name` is no change compared to default behaviour
-                            } else if !path.is_ident("name") {
-                                return err!(path.span(), format!("Unknown attribute property '{}'", path.to_token_stream()));
-                            }
-                        },
-                        Meta::NameValue(name_value) => {
-                            if name_value.path.is_ident("name") {
-                                // Set the literal as the string if it is one
-                                match name_value.value {
-                                    Expr::Lit(ExprLit { lit: Lit::Str(set_name), .. }) => {
-                                        let set_name = set_name.value();
-                                        name = quote!(#set_name);
-                                    },
-                                    expr => {
-                                        return err!(expr.span(), "Name must be a string literal");
-                                    },
-                                }
-                            } else {
-                                return err!(name_value.path.span(), format!("Unknown attribute property '{}'", name_value.path.to_token_stream()));
-                            }
-                        },
+            // Find the variants
+            let variants: Vec<&Ident> = e.variants.iter().map(|v| &v.ident).collect();
+            let indices: Vec<usize> = (0..variants.len()).collect();
+            let infos: Vec<VariantInfo> = match variant_infos(&attrs, &e.variants, follow_serde) {
+                Ok(infos) => infos,
+                Err(err) => return err.into_compile_error().into(),
+            };
+            let mut names: Vec<String> = Vec::with_capacity(infos.len());
+            let mut aliases: Vec<Vec<String>> = Vec::with_capacity(infos.len());
+            let mut hidden: Vec<bool> = Vec::with_capacity(infos.len());
+            let mut deprecated: Vec<Option<Option<String>>> = Vec::with_capacity(infos.len());
+            let mut descriptions: Vec<Option<String>> = Vec::with_capacity(infos.len());
+            for info in infos {
+                names.push(info.name);
+                aliases.push(info.aliases);
+                hidden.push(info.hidden);
+                deprecated.push(info.deprecated);
+                descriptions.push(info.description);
+            }
 
-                        l => {
-                            return err!(l.span(), format!("Unknown attribute property '{}'", l.to_token_stream()));
-                        },
+            // Collect the names by which variants can be found
+            let mut lookup_names: Vec<&str> = names.iter().map(String::as_str).collect();
+            let mut lookup_indices: Vec<usize> = indices.clone();
+            for (i, variant_aliases) in aliases.iter().enumerate() {
+                for alias in variant_aliases {
+                    if !lookup_names.contains(&alias.as_str()) {
+                        lookup_names.push(alias);
+                        lookup_indices.push(i);
                     }
                 }
             }
 
-            // Find the variants
-            let variants: Vec<&Ident> = e.variants.iter().map(|v| &v.ident).collect();
+            // Collect the descriptions of the documented variants
+            let (described, described_descriptions): (Vec<usize>, Vec<&String>) =
+                descriptions.iter().enumerate().filter_map(|(i, desc)| desc.as_ref().map(|desc| (i, desc))).unzip();
+            let variant_description = if !described.is_empty() {
+                Some(quote! {
+                    #[inline]
+                    fn variant_description(index: ::std::primitive::usize) -> ::std::option::Option<&'static ::std::primitive::str> {
+                        match index {
+                            #(#described => ::std::option::Option::Some(#described_descriptions),)*
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+                })
+            } else {
+                None
+            };
+
+            // Collect the hidden variants
+            let hidden_indices: Vec<usize> = hidden.iter().enumerate().filter_map(|(i, hidden)| if *hidden { Some(i) } else { None }).collect();
+            let variant_hidden = if !hidden_indices.is_empty() {
+                Some(quote! {
+                    #[inline]
+                    fn variant_hidden(index: ::std::primitive::usize) -> ::std::primitive::bool { ::std::matches!(index, #(#hidden_indices)|*) }
+                })
+            } else {
+                None
+            };
+
+            // Collect the shapes of the variants
+            let shapes = e.variants.iter().map(|variant| match &variant.fields {
+                Fields::Unit => quote!(::enum_debug::VariantShape::Unit),
+                Fields::Unnamed(fields) => {
+                    let n: usize = fields.unnamed.len();
+                    quote!(::enum_debug::VariantShape::Tuple(#n))
+                },
+                Fields::Named(fields) => {
+                    let names = fields.named.iter().filter_map(|field| field.ident.as_ref().map(|ident| ident.unraw().to_string()));
+                    quote!(::enum_debug::VariantShape::Struct(&[#(#names),*]))
+                },
+            });
 
             // Emit the enum itself, either with generics or without
             let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-            quote! {
+            let mut impls = quote! {
                 impl #impl_generics ::enum_debug::EnumDebug for #ident #ty_generics #where_clause {
                     #[inline]
                     fn type_name() -> &'static ::std::primitive::str { #name }
 
                     fn variant_names() -> &'static [&'static ::std::primitive::str] {
-                        &[#(::std::stringify!(#variants)),*]
+                        &[#(#names),*]
                     }
 
                     fn variant_name(&self) -> &'static ::std::primitive::str {
                         match self {
-                            #(#ident::#variants{ .. } => ::std::stringify!(#variants),)*
+                            #(#ident::#variants{ .. } => #names,)*
                             #[allow(dead_code)]
                             _ => ::std::unreachable!(),
                         }
                     }
+
+                    #[inline]
+                    fn variant_index(&self) -> ::std::primitive::usize {
+                        match self {
+                            #(#ident::#variants{ .. } => #indices,)*
+                            #[allow(dead_code)]
+                            _ => ::std::unreachable!(),
+                        }
+                    }
+
+                    #[inline]
+                    fn variant_index_of(name: &::std::primitive::str) -> ::std::option::Option<::std::primitive::usize> {
+                        match name {
+                            #(#lookup_names => ::std::option::Option::Some(#lookup_indices),)*
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+
+                    #variant_description
+
+                    #variant_hidden
+
+                    #[inline]
+                    fn variant_shape(index: ::std::primitive::usize) -> ::std::option::Option<::enum_debug::VariantShape> {
+                        match index {
+                            #(#indices => ::std::option::Option::Some(#shapes),)*
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+                }
+            };
+
+            // Fingerprint the names if we know them at compile time
+            if let Some(const_name) = &const_name {
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::fingerprint::SchemaFingerprint for #ident #ty_generics #where_clause {
+                        const SCHEMA_FINGERPRINT: ::std::primitive::u64 = ::enum_debug::fingerprint::fingerprint(#const_name, &[#(#names),*]);
+                    }
+                });
+            }
+
+            // Fieldless enums can also be constructed from their variant names
+            let fieldless: bool = e.variants.iter().all(|v| matches!(v.fields, Fields::Unit));
+            if fieldless {
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::FromVariant for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn from_variant_index(index: ::std::primitive::usize) -> ::std::option::Option<Self> {
+                            match index {
+                                #(#indices => ::std::option::Option::Some(Self::#variants),)*
+                                _ => ::std::option::Option::None,
+                            }
+                        }
+                    }
+                });
+            }
+
+            // Implement clap's `ValueEnum` if asked
+            if value_enum {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(value_enum)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "clap") {
+                    return err!(ident.span(), "`enum_debug(value_enum)` requires the `clap`-feature of the `enum-debug` crate");
+                }
+                let values = descriptions.iter().zip(names.iter().zip(aliases.iter().zip(hidden.iter()))).map(|(help, (name, (aliases, hidden)))| {
+                    let help = help.as_ref().map(|help| quote!(.help(#help)));
+                    quote!(::enum_debug::clap::PossibleValue::new(#name)#help #(.alias(#aliases))*.hide(#hidden))
+                });
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::clap::ValueEnum for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn value_variants<'a>() -> &'a [Self] { &[#(Self::#variants),*] }
+
+                        #[inline]
+                        fn to_possible_value(&self) -> ::std::option::Option<::enum_debug::clap::PossibleValue> {
+                            ::std::option::Option::Some(match self {
+                                #(Self::#variants => #values,)*
+                                #[allow(dead_code)]
+                                _ => ::std::unreachable!(),
+                            })
+                        }
+                    }
+                });
+            }
+
+            // Implement schemars' `JsonSchema` if asked
+            if json_schema {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(json_schema)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "schemars") {
+                    return err!(ident.span(), "`enum_debug(json_schema)` requires the `schemars`-feature of the `enum-debug` crate");
                 }
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::schemars::JsonSchema for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn schema_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
+                            ::std::borrow::Cow::Borrowed(<Self as ::enum_debug::EnumDebug>::type_name())
+                        }
+
+                        #[inline]
+                        fn schema_id() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
+                            ::std::borrow::Cow::Borrowed(::std::any::type_name::<Self>())
+                        }
+
+                        #[inline]
+                        fn json_schema(generator: &mut ::enum_debug::schemars::SchemaGenerator) -> ::enum_debug::schemars::Schema {
+                            ::enum_debug::schemars::name_schema::<Self>(generator)
+                        }
+                    }
+                });
+            }
+
+            // Implement async-graphql's enum traits if asked
+            if graphql {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(graphql)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "async-graphql") {
+                    return err!(ident.span(), "`enum_debug(graphql)` requires the `async-graphql`-feature of the `enum-debug` crate");
+                }
+                // NOTE: GraphQL names may not start with `__`, as those are reserved for introspection
+                let is_graphql_name = |name: &str| is_identifier(name, &['_'], &['_']) && !name.starts_with("__");
+                let Some(type_name) = &const_name else {
+                    return err!(ident.span(), "`enum_debug(graphql)` cannot be used together with `enum_debug(path)`");
+                };
+                if !is_graphql_name(type_name) {
+                    return err!(ident.span(), format!("Type name '{type_name}' is not a valid GraphQL name; give one with `enum_debug(name = \"...\")`"));
+                }
+                let mut values: Vec<String> = Vec::with_capacity(names.len());
+                for (variant, name) in e.variants.iter().zip(&names) {
+                    let value: String = convert_case(name, &['-', '_', ' '], true);
+                    if !is_graphql_name(&value) {
+                        return err!(variant.ident.span(), format!("GraphQL value '{value}' of variant '{name}' is not a valid GraphQL name"));
+                    }
+                    if values.contains(&value) {
+                        return err!(variant.ident.span(), format!("GraphQL value '{value}' of variant '{name}' is already used by another variant"));
+                    }
+                    values.push(value);
+                }
+                let description = match doc_summary(&attrs) {
+                    Some(desc) => quote!(::std::option::Option::Some(#desc)),
+                    None => quote!(::std::option::Option::None),
+                };
+                let (deprecated_indices, deprecated_reasons): (Vec<usize>, Vec<_>) = deprecated
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, deprecated)| {
+                        deprecated.as_ref().map(|reason| match reason {
+                            Some(reason) => (i, quote!(::std::option::Option::Some(::std::string::ToString::to_string(#reason)))),
+                            None => (i, quote!(::std::option::Option::None)),
+                        })
+                    })
+                    .unzip();
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::async_graphql::__private::async_graphql::InputType for #ident #ty_generics #where_clause {
+                        type RawValueType = Self;
+
+                        #[inline]
+                        fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> { ::enum_debug::async_graphql::type_name::<Self>() }
+
+                        #[inline]
+                        fn create_type_info(registry: &mut ::enum_debug::async_graphql::__private::async_graphql::registry::Registry) -> ::std::string::String {
+                            ::enum_debug::async_graphql::create_type_info::<Self>(registry, #description, &[#(#values),*], |index| match index {
+                                #(#deprecated_indices => ::enum_debug::async_graphql::Deprecation::Deprecated { reason: #deprecated_reasons },)*
+                                _ => ::enum_debug::async_graphql::Deprecation::NoDeprecated,
+                            })
+                        }
+
+                        #[inline]
+                        fn parse(
+                            value: ::std::option::Option<::enum_debug::async_graphql::__private::async_graphql::Value>,
+                        ) -> ::enum_debug::async_graphql::__private::async_graphql::InputValueResult<Self> {
+                            ::enum_debug::async_graphql::parse::<Self>(value, &[#(#values),*])
+                        }
+
+                        #[inline]
+                        fn to_value(&self) -> ::enum_debug::async_graphql::__private::async_graphql::Value {
+                            ::enum_debug::async_graphql::to_value(self, &[#(#values),*])
+                        }
+
+                        #[inline]
+                        fn as_raw_value(&self) -> ::std::option::Option<&Self::RawValueType> { ::std::option::Option::Some(self) }
+                    }
+
+                    impl #impl_generics ::enum_debug::async_graphql::__private::async_graphql::OutputType for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> { ::enum_debug::async_graphql::type_name::<Self>() }
+
+                        #[inline]
+                        fn create_type_info(registry: &mut ::enum_debug::async_graphql::__private::async_graphql::registry::Registry) -> ::std::string::String {
+                            <Self as ::enum_debug::async_graphql::__private::async_graphql::InputType>::create_type_info(registry)
+                        }
+
+                        async fn resolve(
+                            &self,
+                            _ctx: &::enum_debug::async_graphql::__private::async_graphql::ContextSelectionSet<'_>,
+                            _field: &::enum_debug::async_graphql::__private::async_graphql::Positioned<
+                                ::enum_debug::async_graphql::__private::async_graphql::parser::types::Field,
+                            >,
+                        ) -> ::enum_debug::async_graphql::__private::async_graphql::ServerResult<::enum_debug::async_graphql::__private::async_graphql::Value> {
+                            ::std::result::Result::Ok(::enum_debug::async_graphql::to_value(self, &[#(#values),*]))
+                        }
+                    }
+                });
+            }
+
+            // Export the names to C if asked
+            if let Some(prefix) = c_api {
+                if !cfg!(feature = "ffi") {
+                    return err!(ident.span(), "`enum_debug(c_api)` requires the `ffi`-feature of the `enum-debug` crate");
+                }
+                if !generics.params.is_empty() {
+                    return err!(generics.span(), "`enum_debug(c_api)` can only be used on enums without generics");
+                }
+                if names.iter().any(|name| name.contains('\0')) {
+                    return err!(ident.span(), "`enum_debug(c_api)` can only be used on enums whose variant names do not contain nul-characters");
+                }
+                let cnames = names.iter().map(|name| LitByteStr::new(format!("{name}\0").as_bytes(), ident.span()));
+                let count_fn = Ident::new(&format!("{prefix}_variant_count"), ident.span());
+                let name_fn = Ident::new(&format!("{prefix}_variant_name"), ident.span());
+                impls.extend(quote! {
+                    impl ::enum_debug::ffi::VariantCStrs for #ident {
+                        #[inline]
+                        fn c_prefix() -> &'static ::std::primitive::str { #prefix }
+
+                        #[inline]
+                        fn variant_cstrs() -> &'static [&'static ::std::ffi::CStr] {
+                            const NAMES: &[&::std::ffi::CStr] = &[#(
+                                match ::std::ffi::CStr::from_bytes_with_nul(#cnames) {
+                                    ::std::result::Result::Ok(name) => name,
+                                    ::std::result::Result::Err(_) => ::std::unreachable!(),
+                                }
+                            ),*];
+                            NAMES
+                        }
+                    }
+
+                    const _: () = {
+                        #[no_mangle]
+                        pub extern "C" fn #count_fn() -> ::std::primitive::usize { ::enum_debug::ffi::variant_count::<#ident>() }
+
+                        #[no_mangle]
+                        pub extern "C" fn #name_fn(index: ::std::primitive::usize) -> *const ::std::ffi::c_char {
+                            ::enum_debug::ffi::variant_name_ptr::<#ident>(index)
+                        }
+                    };
+                });
+            }
+
+            // Expose the enum to Python if asked
+            if pyo3 {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(pyo3)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "pyo3") {
+                    return err!(ident.span(), "`enum_debug(pyo3)` requires the `pyo3`-feature of the `enum-debug` crate");
+                }
+                if !generics.params.is_empty() {
+                    return err!(generics.span(), "`enum_debug(pyo3)` can only be used on enums without generics");
+                }
+                impls.extend(quote! {
+                    impl ::enum_debug::pyo3::PyEnum for #ident {
+                        fn py_class(
+                            py: ::enum_debug::pyo3::__private::pyo3::Python<'_>,
+                        ) -> ::enum_debug::pyo3::__private::pyo3::PyResult<
+                            &::enum_debug::pyo3::__private::pyo3::Bound<'_, ::enum_debug::pyo3::__private::pyo3::types::PyType>,
+                        > {
+                            static CLASS: ::enum_debug::pyo3::__private::pyo3::sync::PyOnceLock<
+                                ::enum_debug::pyo3::__private::pyo3::Py<::enum_debug::pyo3::__private::pyo3::types::PyType>,
+                            > = ::enum_debug::pyo3::__private::pyo3::sync::PyOnceLock::new();
+                            CLASS.get_or_try_init(py, || ::enum_debug::pyo3::create_class::<Self>(py)).map(|class| class.bind(py))
+                        }
+                    }
+
+                    impl<'py> ::enum_debug::pyo3::__private::pyo3::IntoPyObject<'py> for #ident {
+                        type Target = ::enum_debug::pyo3::__private::pyo3::PyAny;
+                        type Output = ::enum_debug::pyo3::__private::pyo3::Bound<'py, ::enum_debug::pyo3::__private::pyo3::PyAny>;
+                        type Error = ::enum_debug::pyo3::__private::pyo3::PyErr;
+
+                        #[inline]
+                        fn into_pyobject(self, py: ::enum_debug::pyo3::__private::pyo3::Python<'py>) -> ::std::result::Result<Self::Output, Self::Error> {
+                            ::enum_debug::pyo3::to_python(py, &self)
+                        }
+                    }
+
+                    impl<'py> ::enum_debug::pyo3::__private::pyo3::IntoPyObject<'py> for &#ident {
+                        type Target = ::enum_debug::pyo3::__private::pyo3::PyAny;
+                        type Output = ::enum_debug::pyo3::__private::pyo3::Bound<'py, ::enum_debug::pyo3::__private::pyo3::PyAny>;
+                        type Error = ::enum_debug::pyo3::__private::pyo3::PyErr;
+
+                        #[inline]
+                        fn into_pyobject(self, py: ::enum_debug::pyo3::__private::pyo3::Python<'py>) -> ::std::result::Result<Self::Output, Self::Error> {
+                            ::enum_debug::pyo3::to_python(py, self)
+                        }
+                    }
+
+                    impl<'a, 'py> ::enum_debug::pyo3::__private::pyo3::FromPyObject<'a, 'py> for #ident {
+                        type Error = ::enum_debug::pyo3::__private::pyo3::PyErr;
+
+                        #[inline]
+                        fn extract(
+                            obj: ::enum_debug::pyo3::__private::pyo3::Borrowed<'a, 'py, ::enum_debug::pyo3::__private::pyo3::PyAny>,
+                        ) -> ::std::result::Result<Self, Self::Error> {
+                            ::enum_debug::pyo3::from_python(obj)
+                        }
+                    }
+                });
+            }
+
+            // Implement rusqlite's conversion traits if asked
+            if rusqlite {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(rusqlite)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "rusqlite") {
+                    return err!(ident.span(), "`enum_debug(rusqlite)` requires the `rusqlite`-feature of the `enum-debug` crate");
+                }
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::rusqlite::__private::rusqlite::types::ToSql for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn to_sql(&self) -> ::enum_debug::rusqlite::__private::rusqlite::Result<::enum_debug::rusqlite::__private::rusqlite::types::ToSqlOutput<'_>> {
+                            ::std::result::Result::Ok(::enum_debug::rusqlite::to_sql(self))
+                        }
+                    }
+
+                    impl #impl_generics ::enum_debug::rusqlite::__private::rusqlite::types::FromSql for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn column_result(
+                            value: ::enum_debug::rusqlite::__private::rusqlite::types::ValueRef<'_>,
+                        ) -> ::enum_debug::rusqlite::__private::rusqlite::types::FromSqlResult<Self> {
+                            ::enum_debug::rusqlite::column_result(value)
+                        }
+                    }
+                });
+            }
+
+            // Implement log's `ToValue` if asked
+            if log {
+                if !cfg!(feature = "log") {
+                    return err!(ident.span(), "`enum_debug(log)` requires the `log`-feature of the `enum-debug` crate");
+                }
+                impls.extend(quote! {
+                    impl #impl_generics ::enum_debug::log::__private::log::kv::ToValue for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn to_value(&self) -> ::enum_debug::log::__private::log::kv::Value<'_> { ::enum_debug::log::to_value(self) }
+                    }
+                });
+            }
+
+            // Implement sqlx' conversion traits if asked
+            if sqlx {
+                if !fieldless {
+                    return err!(ident.span(), "`enum_debug(sqlx)` can only be used on enums without fields");
+                }
+                if !cfg!(feature = "sqlx") {
+                    return err!(ident.span(), "`enum_debug(sqlx)` requires the `sqlx`-feature of the `enum-debug` crate");
+                }
+                if !generics.params.is_empty() {
+                    return err!(generics.span(), "`enum_debug(sqlx)` can only be used on enums without generics");
+                }
+                impls.extend(quote! {
+                    impl<DB: ::enum_debug::sqlx::__private::sqlx::Database> ::enum_debug::sqlx::__private::sqlx::Type<DB> for #ident
+                    where
+                        ::std::primitive::str: ::enum_debug::sqlx::__private::sqlx::Type<DB>,
+                    {
+                        #[inline]
+                        fn type_info() -> DB::TypeInfo { ::enum_debug::sqlx::type_info::<DB>() }
+
+                        #[inline]
+                        fn compatible(ty: &DB::TypeInfo) -> ::std::primitive::bool { ::enum_debug::sqlx::compatible::<DB>(ty) }
+                    }
+
+                    impl<'q, DB: ::enum_debug::sqlx::__private::sqlx::Database> ::enum_debug::sqlx::__private::sqlx::Encode<'q, DB> for #ident
+                    where
+                        &'static ::std::primitive::str: ::enum_debug::sqlx::__private::sqlx::Encode<'q, DB>,
+                    {
+                        #[inline]
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <DB as ::enum_debug::sqlx::__private::sqlx::Database>::ArgumentBuffer,
+                        ) -> ::std::result::Result<::enum_debug::sqlx::__private::sqlx::encode::IsNull, ::enum_debug::sqlx::__private::sqlx::error::BoxDynError> {
+                            ::enum_debug::sqlx::encode::<DB, Self>(self, buf)
+                        }
+                    }
+
+                    impl<'r, DB: ::enum_debug::sqlx::__private::sqlx::Database> ::enum_debug::sqlx::__private::sqlx::Decode<'r, DB> for #ident
+                    where
+                        &'r ::std::primitive::str: ::enum_debug::sqlx::__private::sqlx::Decode<'r, DB>,
+                    {
+                        #[inline]
+                        fn decode(
+                            value: <DB as ::enum_debug::sqlx::__private::sqlx::Database>::ValueRef<'r>,
+                        ) -> ::std::result::Result<Self, ::enum_debug::sqlx::__private::sqlx::error::BoxDynError> {
+                            ::enum_debug::sqlx::decode::<DB, Self>(value)
+                        }
+                    }
+                });
             }
-            .into()
+            impls.into()
         },
 
         // Can only do enums, clearly
@@ -0,0 +1,517 @@
+//  PARSE.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:09:39
+//  Last edited:
+//    19 Oct 2026, 01:45:32
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Parses the `#[enum_debug(...)]` (and `#[serde(...)]`) attributes of
+//!   enums into the names they will get.
+//!
+//!   This module only depends on `syn`, so that tools that analyse source
+//!   code (like the `enum-debug` CLI) can include it to find the same names as
+//!   the derive macro.
+//
+
+use quote::ToTokens as _;
+use syn::punctuated::Punctuated;
+use syn::ext::IdentExt as _;
+use syn::spanned::Spanned as _;
+use syn::token::Comma;
+use syn::{Attribute, Expr, ExprLit, Ident, Lit, Meta, Variant};
+
+
+/***** AUXILLARY *****/
+/// The options given in `#[enum_debug(...)]` attributes on an enum.
+pub struct ContainerOptions {
+    /// The type name of the enum, which is its identifier unless overridden with `name = "..."`.
+    /// [`None`] if `path` is given, as the name is only known at compile time then.
+    pub type_name:    Option<String>,
+    /// Whether the variants are named like serde names them (`follow_serde`).
+    pub follow_serde: bool,
+    /// Whether clap's `ValueEnum` should be implemented (`value_enum`).
+    pub value_enum:   bool,
+    /// Whether schemars' `JsonSchema` should be implemented (`json_schema`).
+    pub json_schema:  bool,
+    /// Whether async-graphql's `InputType` and `OutputType` should be implemented (`graphql`).
+    pub graphql:      bool,
+    /// Whether the enum should be exposed to Python (`pyo3`).
+    pub pyo3:         bool,
+    /// Whether rusqlite's `ToSql` and `FromSql` should be implemented (`rusqlite`).
+    pub rusqlite:     bool,
+    /// Whether sqlx' `Type`, `Encode` and `Decode` should be implemented (`sqlx`).
+    pub sqlx:         bool,
+    /// Whether log's `ToValue` should be implemented (`log`).
+    pub log:          bool,
+    /// The prefix of the functions exporting the names to C, if any (`c_api`).
+    pub c_api:        Option<String>,
+}
+
+/// The options given in `#[enum_debug(...)]` attributes on a variant.
+struct VariantOptions {
+    /// Additional names given with `alias = "..."`.
+    aliases:    Vec<String>,
+    /// Whether the variant is marked as `hidden`.
+    hidden:     bool,
+    /// Whether the variant is marked as `deprecated`, and if so, the reason given with
+    /// `deprecated = "..."`.
+    deprecated: Option<Option<String>>,
+}
+
+/// Everything the attributes of an enum say about one of its variants.
+pub struct VariantInfo {
+    /// The name of the variant.
+    pub name:        String,
+    /// The additional names by which the variant can be found.
+    pub aliases:     Vec<String>,
+    /// Whether the variant is marked as `hidden`.
+    pub hidden:      bool,
+    /// Whether the variant is marked as `deprecated`, and if so, the reason given.
+    pub deprecated:  Option<Option<String>>,
+    /// The first paragraph of the doc comments on the variant, if any.
+    pub description: Option<String>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Collects the properties in all `#[serde(...)]` attributes.
+///
+/// # Arguments
+/// - `attrs`: The attributes to search.
+///
+/// # Returns
+/// A list of [`Meta`]s, one per property.
+///
+/// # Errors
+/// This function errors if any `#[serde(...)]` attribute did not consist of valid properties.
+fn serde_metas(attrs: &[Attribute]) -> Result<Vec<Meta>, syn::Error> {
+    let mut res: Vec<Meta> = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("serde") {
+            res.extend(attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?);
+        }
+    }
+    Ok(res)
+}
+
+/// Gets the serialized string from a serde property like `rename` or `rename_all`.
+///
+/// Serde accepts both `rename = "..."` and `rename(serialize = "...", deserialize = "...")`. In
+/// the latter case, the `serialize` value is returned.
+///
+/// # Arguments
+/// - `meta`: The property to get the value of.
+///
+/// # Returns
+/// The string value for serialization, or [`None`] if the property only applies to
+/// deserialization.
+///
+/// # Errors
+/// This function errors if the property was not a string literal or a list of them.
+fn serde_serialize_value(meta: &Meta) -> Result<Option<String>, syn::Error> {
+    match meta {
+        Meta::NameValue(name_value) => match &name_value.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) => Ok(Some(value.value())),
+            expr => Err(syn::Error::new(expr.span(), "Expected a string literal")),
+        },
+        Meta::List(list) => {
+            for meta in list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)? {
+                if meta.path().is_ident("serialize") {
+                    return serde_serialize_value(&meta);
+                }
+            }
+            Ok(None)
+        },
+        Meta::Path(path) => Err(syn::Error::new(path.span(), "Expected a value")),
+    }
+}
+
+/// Applies one of serde's `rename_all` rules to a variant name.
+///
+/// # Arguments
+/// - `rule`: The name of the rule, e.g., `snake_case`.
+/// - `name`: The (PascalCase) name of the variant to rename.
+///
+/// # Returns
+/// The renamed variant name, or [`None`] if `rule` is not a rule known to serde.
+fn serde_rename(rule: &str, name: &str) -> Option<String> {
+    let snake_case = || {
+        let mut res: String = String::with_capacity(name.len() + 4);
+        for (i, c) in name.char_indices() {
+            if i > 0 && c.is_uppercase() {
+                res.push('_');
+            }
+            res.push(c.to_ascii_lowercase());
+        }
+        res
+    };
+    match rule {
+        "lowercase" => Some(name.to_ascii_lowercase()),
+        "UPPERCASE" => Some(name.to_ascii_uppercase()),
+        "PascalCase" => Some(name.into()),
+        "camelCase" => {
+            let mut chars = name.chars();
+            Some(chars.next().map(|c| c.to_ascii_lowercase().to_string() + chars.as_str()).unwrap_or_default())
+        },
+        "snake_case" => Some(snake_case()),
+        "SCREAMING_SNAKE_CASE" => Some(snake_case().to_ascii_uppercase()),
+        "kebab-case" => Some(snake_case().replace('_', "-")),
+        "SCREAMING-KEBAB-CASE" => Some(snake_case().to_ascii_uppercase().replace('_', "-")),
+        _ => None,
+    }
+}
+
+/// Checks whether the given string is an ASCII identifier.
+///
+/// # Arguments
+/// - `name`: The name to check.
+/// - `first`: The characters other than ASCII letters allowed at the start of the name.
+/// - `rest`: The characters other than ASCII letters and digits allowed in the rest of the name.
+///
+/// # Returns
+/// True if `name` is non-empty and consists of the allowed characters, or false otherwise.
+pub fn is_identifier(name: &str, first: &[char], rest: &[char]) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || first.contains(&c)) && chars.all(|c| c.is_ascii_alphanumeric() || rest.contains(&c))
+}
+
+/// Converts a name to snake_case or SCREAMING_SNAKE_CASE, keeping acronyms together.
+///
+/// Words are split at changes from lowercase (or digits) to uppercase, before the last uppercase
+/// letter of an acronym, and at the given separators. For example, `HTTPServer` becomes
+/// `http_server`.
+///
+/// Mirrors the conversion used by the generators in the `enum-debug` crate, which cannot be
+/// shared with a proc-macro crate.
+///
+/// # Arguments
+/// - `name`: The name to convert.
+/// - `separators`: The characters that separate words. They are replaced by a single `_`.
+/// - `upper`: Whether to convert to SCREAMING_SNAKE_CASE instead of snake_case.
+///
+/// # Returns
+/// The converted name.
+pub fn convert_case(name: &str, separators: &[char], upper: bool) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut res: String = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if separators.contains(c) {
+            if !res.is_empty() && !res.ends_with('_') {
+                res.push('_');
+            }
+            continue;
+        }
+        if c.is_uppercase() && i > 0 && !res.ends_with('_') {
+            let prev: char = chars[i - 1];
+            let next_lower: bool = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                res.push('_');
+            }
+        }
+        if upper {
+            res.extend(c.to_uppercase());
+        } else {
+            res.extend(c.to_lowercase());
+        }
+    }
+    res
+}
+
+/// Computes the names of variants as serde would serialize them.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the enum itself, which may carry `#[serde(rename_all = "...")]`.
+/// - `variants`: The variants of the enum, which may carry `#[serde(rename = "...")]` and
+///   `#[serde(alias = "...")]`.
+///
+/// Note that variants with `#[serde(skip)]` are still named, as every variant needs a name.
+///
+/// # Returns
+/// A pair of the serialized name of every variant and the aliases of every variant.
+///
+/// # Errors
+/// This function errors if any of the serde attributes is malformed.
+fn serde_names<'v>(attrs: &[Attribute], variants: impl IntoIterator<Item = &'v Variant>) -> Result<(Vec<String>, Vec<Vec<String>>), syn::Error> {
+    // Find the container-wide rule, if any
+    let mut rule: Option<(String, Meta)> = None;
+    for meta in serde_metas(attrs)? {
+        if meta.path().is_ident("rename_all") {
+            if let Some(value) = serde_serialize_value(&meta)? {
+                rule = Some((value, meta));
+            }
+        }
+    }
+
+    // Now find the name of every variant
+    let mut names: Vec<String> = Vec::new();
+    let mut aliases: Vec<Vec<String>> = Vec::new();
+    for variant in variants {
+        let mut name: Option<String> = None;
+        let mut variant_aliases: Vec<String> = Vec::new();
+        for meta in serde_metas(&variant.attrs)? {
+            if meta.path().is_ident("rename") {
+                if let Some(value) = serde_serialize_value(&meta)? {
+                    name = Some(value);
+                }
+            } else if meta.path().is_ident("alias") {
+                if let Some(value) = serde_serialize_value(&meta)? {
+                    variant_aliases.push(value);
+                }
+            }
+        }
+
+        // Use the rule if there was no explicit rename
+        let ident: String = variant.ident.unraw().to_string();
+        let name: String = match (name, &rule) {
+            (Some(name), _) => name,
+            (None, Some((rule, meta))) => match serde_rename(rule, &ident) {
+                Some(name) => name,
+                None => return Err(syn::Error::new(meta.span(), format!("Unknown serde rename rule '{rule}'"))),
+            },
+            (None, None) => ident,
+        };
+
+        names.push(name);
+        aliases.push(variant_aliases);
+    }
+    Ok((names, aliases))
+}
+
+
+
+
+/// Parses the `#[enum_debug(...)]` attributes on a variant.
+///
+/// # Arguments
+/// - `variant`: The variant to parse the attributes of.
+///
+/// # Returns
+/// A [`VariantOptions`] with the parsed attributes.
+///
+/// # Errors
+/// This function errors if any of the attributes is malformed or unknown.
+fn variant_options(variant: &Variant) -> Result<VariantOptions, syn::Error> {
+    let mut opts: VariantOptions = VariantOptions { aliases: Vec::new(), hidden: false, deprecated: None };
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("enum_debug") {
+            continue;
+        }
+        for meta in attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)? {
+            match meta {
+                Meta::Path(path) if path.is_ident("hidden") => opts.hidden = true,
+                Meta::Path(path) if path.is_ident("deprecated") => opts.deprecated = Some(None),
+                Meta::NameValue(name_value) if name_value.path.is_ident("alias") => match name_value.value {
+                    Expr::Lit(ExprLit { lit: Lit::Str(alias), .. }) => opts.aliases.push(alias.value()),
+                    expr => return Err(syn::Error::new(expr.span(), "Alias must be a string literal")),
+                },
+                Meta::NameValue(name_value) if name_value.path.is_ident("deprecated") => match name_value.value {
+                    Expr::Lit(ExprLit { lit: Lit::Str(reason), .. }) => opts.deprecated = Some(Some(reason.value())),
+                    expr => return Err(syn::Error::new(expr.span(), "Deprecation reason must be a string literal")),
+                },
+                meta => return Err(syn::Error::new(meta.span(), format!("Unknown attribute property '{}'", meta.path().to_token_stream()))),
+            }
+        }
+    }
+    Ok(opts)
+}
+
+/// Collects the first paragraph of the doc comments on an item.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the item.
+///
+/// # Returns
+/// The lines of the first paragraph, trimmed and joined by spaces, or [`None`] if the item isn't
+/// documented.
+pub fn doc_summary(attrs: &[Attribute]) -> Option<String> {
+    let mut summary: String = String::new();
+    for attr in attrs {
+        if let Meta::NameValue(name_value) = &attr.meta {
+            if let (true, Expr::Lit(ExprLit { lit: Lit::Str(line), .. })) = (name_value.path.is_ident("doc"), &name_value.value) {
+                let line: String = line.value();
+                let line: &str = line.trim();
+                if line.is_empty() {
+                    // Stop at the end of the first paragraph
+                    if !summary.is_empty() {
+                        break;
+                    }
+                    continue;
+                }
+                if !summary.is_empty() {
+                    summary.push(' ');
+                }
+                summary.push_str(line);
+            }
+        }
+    }
+    if !summary.is_empty() { Some(summary) } else { None }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Parses the `#[enum_debug(...)]` attributes on an enum.
+///
+/// # Arguments
+/// - `ident`: The identifier of the enum.
+/// - `attrs`: The attributes of the enum.
+///
+/// # Returns
+/// A [`ContainerOptions`] with the parsed attributes.
+///
+/// # Errors
+/// This function errors if any of the attributes is malformed or unknown.
+pub fn container_options(ident: &Ident, attrs: &[Attribute]) -> Result<ContainerOptions, syn::Error> {
+    let mut opts: ContainerOptions = ContainerOptions {
+        type_name:    Some(ident.to_string()),
+        follow_serde: false,
+        value_enum:   false,
+        json_schema:  false,
+        graphql:      false,
+        pyo3:         false,
+        rusqlite:     false,
+        sqlx:         false,
+        log:          false,
+        c_api:        None,
+    };
+    for attr in attrs {
+        // Only do our own
+        if !attr.path().is_ident("enum_debug") {
+            continue;
+        }
+
+        // Attempt to parse the list
+        let metas: Punctuated<Meta, Comma> = match attr.parse_args_with(Punctuated::parse_terminated) {
+            Ok(metas) => metas,
+            // Not for us
+            Err(err) => return Err(syn::Error::new(err.span(), "Failed to parse `enum_debug(...)` arguments as valid attributes")),
+        };
+
+        // Parse the attributes
+        for meta in metas {
+            match meta {
+                Meta::Path(path) => {
+                    if path.is_ident("path") {
+                        // Override with the path, which is only known at compile time
+                        opts.type_name = None;
+                    } else if path.is_ident("follow_serde") {
+                        // Use serde's names for the variants
+                        opts.follow_serde = true;
+                    } else if path.is_ident("value_enum") {
+                        // Also implement clap's `ValueEnum`
+                        opts.value_enum = true;
+                    } else if path.is_ident("json_schema") {
+                        // Also implement schemars' `JsonSchema`
+                        opts.json_schema = true;
+                    } else if path.is_ident("graphql") {
+                        // Also implement async-graphql's `InputType` and `OutputType`
+                        opts.graphql = true;
+                    } else if path.is_ident("pyo3") {
+                        // Also expose the enum to Python
+                        opts.pyo3 = true;
+                    } else if path.is_ident("rusqlite") {
+                        // Also implement rusqlite's `ToSql` and `FromSql`
+                        opts.rusqlite = true;
+                    } else if path.is_ident("sqlx") {
+                        // Also implement sqlx' `Type`, `Encode` and `Decode`
+                        opts.sqlx = true;
+                    } else if path.is_ident("log") {
+                        // Also implement log's `ToValue`
+                        opts.log = true;
+                    } else if path.is_ident("c_api") {
+                        // Also export the names to C, with a prefix based on the identifier
+                        let prefix: String = convert_case(&ident.unraw().to_string(), &[], false);
+                        if !is_identifier(&prefix, &['_'], &['_']) {
+                            return Err(syn::Error::new(
+                                path.span(),
+                                format!("Default C API prefix '{prefix}' is not a valid C identifier; give one with `c_api = \"...\"`"),
+                            ));
+                        }
+                        opts.c_api = Some(prefix);
+                    // NOTE: Legacy here, path used to be the default but now `name` is no change compared to default behaviour
+                    } else if !path.is_ident("name") {
+                        return Err(syn::Error::new(path.span(), format!("Unknown attribute property '{}'", path.to_token_stream())));
+                    }
+                },
+                Meta::NameValue(name_value) => {
+                    if name_value.path.is_ident("name") {
+                        // Set the literal as the string if it is one
+                        match name_value.value {
+                            Expr::Lit(ExprLit { lit: Lit::Str(set_name), .. }) => opts.type_name = Some(set_name.value()),
+                            expr => return Err(syn::Error::new(expr.span(), "Name must be a string literal")),
+                        }
+                    } else if name_value.path.is_ident("c_api") {
+                        // Also export the names to C, with the given prefix
+                        match name_value.value {
+                            Expr::Lit(ExprLit { lit: Lit::Str(prefix), .. }) => {
+                                if !is_identifier(&prefix.value(), &['_'], &['_']) {
+                                    return Err(syn::Error::new(prefix.span(), format!("C API prefix '{}' is not a valid C identifier", prefix.value())));
+                                }
+                                opts.c_api = Some(prefix.value());
+                            },
+                            expr => return Err(syn::Error::new(expr.span(), "C API prefix must be a string literal")),
+                        }
+                    } else {
+                        return Err(syn::Error::new(
+                            name_value.path.span(),
+                            format!("Unknown attribute property '{}'", name_value.path.to_token_stream()),
+                        ));
+                    }
+                },
+
+                l => return Err(syn::Error::new(l.span(), format!("Unknown attribute property '{}'", l.to_token_stream()))),
+            }
+        }
+    }
+    Ok(opts)
+}
+
+/// Parses the attributes on the variants of an enum.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the enum itself.
+/// - `variants`: The variants of the enum.
+/// - `follow_serde`: Whether to name the variants like serde does (see
+///   [`ContainerOptions::follow_serde`]).
+///
+/// # Returns
+/// A [`VariantInfo`] for every variant, in declaration order.
+///
+/// # Errors
+/// This function errors if any of the attributes is malformed or unknown, or if two variants end
+/// up with the same name or alias (as looking them up would be ambiguous).
+pub fn variant_infos<'v>(
+    attrs: &[Attribute],
+    variants: impl Clone + IntoIterator<Item = &'v Variant>,
+    follow_serde: bool,
+) -> Result<Vec<VariantInfo>, syn::Error> {
+    let (names, aliases): (Vec<String>, Vec<Vec<String>>) = if follow_serde {
+        serde_names(attrs, variants.clone())?
+    } else {
+        variants.clone().into_iter().map(|v| (v.ident.to_string(), Vec::new())).unzip()
+    };
+
+    let mut res: Vec<VariantInfo> = Vec::with_capacity(names.len());
+    for ((variant, name), mut aliases) in variants.into_iter().zip(names).zip(aliases) {
+        let opts: VariantOptions = variant_options(variant)?;
+        aliases.extend(opts.aliases);
+
+        // Assert the names are unique, as lookups would be ambiguous otherwise
+        for name in std::iter::once(&name).chain(&aliases) {
+            if res.iter().any(|other: &VariantInfo| other.name == *name || other.aliases.contains(name)) {
+                return Err(syn::Error::new(variant.ident.span(), format!("Variant name or alias '{name}' is already used by another variant")));
+            }
+        }
+        res.push(VariantInfo { name, aliases, hidden: opts.hidden, deprecated: opts.deprecated, description: doc_summary(&variant.attrs) });
+    }
+    Ok(res)
+}
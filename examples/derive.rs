@@ -4,7 +4,7 @@
 //  Created:
 //    22 Jul 2024, 22:33:34
 //  Last edited:
-//    23 Jul 2024, 00:04:17
+//    18 Oct 2026, 23:26:11
 //  Auto updated?
 //    Yes
 //
@@ -13,6 +13,7 @@
 //
 
 use enum_debug::EnumDebug;
+use serde::Serialize;
 
 
 /***** EXAMPLES *****/
@@ -42,6 +43,24 @@ pub enum Modded2 {
     VariantWithoutValue,
 }
 
+#[derive(EnumDebug, Serialize)]
+#[enum_debug(follow_serde)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+pub enum Serded1 {
+    VariantWithoutValue,
+    VariantWithValue(String),
+    #[serde(rename(serialize = "struct", deserialize = "structure"), alias = "VariantWithStruct")]
+    VariantWithStruct {
+        field: String,
+    },
+}
+#[derive(EnumDebug, Serialize)]
+#[enum_debug(follow_serde)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub enum Serded2 {
+    VariantWithoutValue,
+}
+
 
 fn main() {
     assert_eq!(&format!("{}", One::VariantWithoutValue.variant()), "VariantWithoutValue");
@@ -58,4 +77,17 @@ fn main() {
     assert_eq!(&format!("{:?}", Modded1::VariantWithoutValue.variant()), "Foo::VariantWithoutValue");
     assert_eq!(&format!("{}", Modded2::VariantWithoutValue.variant()), "VariantWithoutValue");
     assert_eq!(&format!("{:?}", Modded2::VariantWithoutValue.variant()), "derive::Modded2::VariantWithoutValue");
+
+    assert_eq!(One::VariantWithoutValue.variant_index(), 0);
+    assert_eq!(More::VariantWithoutValue.variant_index(), 0);
+    assert_eq!(More::VariantWithValue("foo".into()).variant_index(), 1);
+    assert_eq!(More::VariantWithStruct { field: "foo".into() }.variant_index(), 2);
+
+    assert_eq!(Serded1::variant_names(), &["VARIANT-WITHOUT-VALUE", "VARIANT-WITH-VALUE", "struct"]);
+    for value in [Serded1::VariantWithoutValue, Serded1::VariantWithValue("foo".into()), Serded1::VariantWithStruct { field: "foo".into() }] {
+        let json: String = serde_json::to_string(&value).unwrap();
+        assert!(json.starts_with(&format!("\"{}\"", value.variant_name())) || json.starts_with(&format!("{{\"{}\"", value.variant_name())));
+    }
+    assert_eq!(Serded1::variant_index_of("VariantWithStruct"), Some(2));
+    assert_eq!(&format!("{:?}", Serded2::VariantWithoutValue.variant()), "Serded2::variantWithoutValue");
 }
@@ -0,0 +1,29 @@
+//  FFI.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:48:02
+//  Last edited:
+//    18 Oct 2026, 23:48:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to export the variant names of an enum to C.
+//!
+//!   This example is built as a static library, which `tests/ffi.rs` links
+//!   into a C program.
+//
+
+use enum_debug::EnumDebug;
+
+
+/***** EXAMPLES *****/
+#[derive(EnumDebug)]
+#[enum_debug(c_api)]
+pub enum Jedi {
+    ObiWanKenobi,
+    AnakinSkywalker,
+    MaceWindu,
+    MasterYoda,
+}
@@ -0,0 +1,247 @@
+//  ARROW.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:00:29
+//  Last edited:
+//    19 Oct 2026, 00:03:15
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with Apache Arrow for [`EnumDebug`] types.
+//!
+//!   Columns of enums are most compactly stored as a [`DictionaryArray`]:
+//!   the dictionary holds [`EnumDebug::variant_names()`], and every row is
+//!   just the index of its variant. Use [`field()`] to describe such a column,
+//!   [`to_dictionary_array()`] to build one and [`from_dictionary_array()`] to
+//!   read it back:
+//!   ```rust
+//!   use std::sync::Arc;
+//!
+//!   use arrow_array::types::Int8Type;
+//!   use arrow_array::{Array, DictionaryArray, RecordBatch};
+//!   use arrow_schema::Schema;
+//!   use enum_debug::arrow::{field, from_dictionary_array, to_dictionary_array, validate_field};
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(Debug, EnumDebug, PartialEq)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!       MaceWindu,
+//!   }
+//!
+//!   let jedi = [Jedi::AnakinSkywalker, Jedi::ObiWanKenobi, Jedi::AnakinSkywalker];
+//!   let array: DictionaryArray<Int8Type> = to_dictionary_array(&jedi).unwrap();
+//!   assert_eq!(array.keys().values(), &[1, 0, 1]);
+//!
+//!   let schema = Schema::new(vec![field::<Int8Type, Jedi>("jedi", false)]);
+//!   let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).unwrap();
+//!
+//!   // Reading it back, somewhere else
+//!   validate_field::<Jedi>(batch.schema().field(0)).unwrap();
+//!   let column = batch.column(0).as_any().downcast_ref::<DictionaryArray<Int8Type>>().unwrap();
+//!   assert_eq!(
+//!       from_dictionary_array::<Int8Type, Jedi>(column).unwrap(),
+//!       [Some(Jedi::AnakinSkywalker), Some(Jedi::ObiWanKenobi), Some(Jedi::AnakinSkywalker)]
+//!   );
+//!   ```
+//!
+//!   This module is only available when the `arrow`-feature is used.
+//
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::sync::Arc;
+
+use ::arrow_array::types::ArrowDictionaryKeyType;
+use ::arrow_array::{Array, DictionaryArray, PrimitiveArray, StringArray};
+use ::arrow_buffer::ArrowNativeType as _;
+use ::arrow_schema::{DataType, Field};
+
+use crate::{EnumDebug, FromVariant, UnknownVariantError};
+
+
+/***** CONSTANTS *****/
+/// The key in the metadata of a [`Field`] under which the [`EnumDebug::type_name()`] of its
+/// values is stored.
+pub const TYPE_NAME_KEY: &str = "enum_debug.type_name";
+
+
+
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when converting [`EnumDebug`] types to or from Arrow arrays.
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// A type has more variants than the key type of the dictionary can index.
+    TooManyVariants { type_name: &'static str, variants: usize, key_type: DataType },
+    /// The values of a dictionary are not strings.
+    IllegalDictionary { data_type: DataType },
+    /// A row refers to a name in the dictionary that is not a variant.
+    UnknownVariant { err: UnknownVariantError },
+    /// A field does not describe a dictionary of strings.
+    IllegalFieldType { field: String, data_type: DataType },
+    /// A field does not record the type name of its values.
+    MissingTypeName { field: String },
+    /// A field records a different type name than expected.
+    TypeNameMismatch { field: String, expected: &'static str, got: String },
+}
+impl Display for DictionaryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use DictionaryError::*;
+        match self {
+            TooManyVariants { type_name, variants, key_type } => {
+                write!(f, "Type {type_name} has {variants} variants, which is too many to index with dictionary keys of type {key_type}")
+            },
+            IllegalDictionary { data_type } => write!(f, "Expected a dictionary of strings, got a dictionary of {data_type}"),
+            UnknownVariant { .. } => write!(f, "Dictionary contains a name that is not a variant"),
+            IllegalFieldType { field, data_type } => write!(f, "Field '{field}' has type {data_type}, expected a dictionary of strings"),
+            MissingTypeName { field } => write!(f, "Field '{field}' has no '{TYPE_NAME_KEY}' metadata"),
+            TypeNameMismatch { field, expected, got } => write!(f, "Field '{field}' contains values of type {got}, expected {expected}"),
+        }
+    }
+}
+impl Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use DictionaryError::*;
+        match self {
+            UnknownVariant { err } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Describes a column that contains an [`EnumDebug`] type as a dictionary.
+///
+/// # Arguments
+/// - `name`: The name of the column.
+/// - `nullable`: Whether the column may contain nulls.
+///
+/// # Returns
+/// A [`Field`] with a dictionary type with keys of `K` and strings as values. Its metadata
+/// records [`EnumDebug::type_name()`] under [`TYPE_NAME_KEY`].
+pub fn field<K: ArrowDictionaryKeyType, T: ?Sized + EnumDebug>(name: impl Into<String>, nullable: bool) -> Field {
+    Field::new(name, DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(DataType::Utf8)), nullable)
+        .with_metadata(HashMap::from([(TYPE_NAME_KEY.into(), T::type_name().into())]))
+}
+
+/// Checks that a column contains an [`EnumDebug`] type as a dictionary.
+///
+/// Use this to validate a column before reading it with [`from_dictionary_array()`].
+///
+/// # Arguments
+/// - `field`: The [`Field`] describing the column.
+///
+/// # Errors
+/// This function errors if the field does not have a dictionary type with strings as values, or
+/// if its metadata does not record [`EnumDebug::type_name()`] of `T` under [`TYPE_NAME_KEY`].
+pub fn validate_field<T: ?Sized + EnumDebug>(field: &Field) -> Result<(), DictionaryError> {
+    if !matches!(field.data_type(), DataType::Dictionary(_, values) if **values == DataType::Utf8) {
+        return Err(DictionaryError::IllegalFieldType { field: field.name().clone(), data_type: field.data_type().clone() });
+    }
+    match field.metadata().get(TYPE_NAME_KEY) {
+        Some(name) if name == T::type_name() => Ok(()),
+        Some(name) => Err(DictionaryError::TypeNameMismatch { field: field.name().clone(), expected: T::type_name(), got: name.clone() }),
+        None => Err(DictionaryError::MissingTypeName { field: field.name().clone() }),
+    }
+}
+
+
+
+/// Converts values of an [`EnumDebug`] type to a [`DictionaryArray`].
+///
+/// # Arguments
+/// - `values`: The values to convert.
+///
+/// # Returns
+/// A [`DictionaryArray`] whose dictionary is exactly [`EnumDebug::variant_names()`] (in
+/// declaration order), and whose keys are the [`EnumDebug::variant_index()`] of every value.
+///
+/// # Errors
+/// This function errors if `T` has more variants than `K` can index.
+pub fn to_dictionary_array<K: ArrowDictionaryKeyType, T: EnumDebug>(values: impl IntoIterator<Item = T>) -> Result<DictionaryArray<K>, DictionaryError> {
+    let variants: &'static [&'static str] = T::variant_names();
+    if !variants.is_empty() && K::Native::from_usize(variants.len() - 1).is_none() {
+        return Err(DictionaryError::TooManyVariants { type_name: T::type_name(), variants: variants.len(), key_type: K::DATA_TYPE });
+    }
+
+    // The check above guarantees every index fits
+    let keys: PrimitiveArray<K> = PrimitiveArray::from_iter_values(values.into_iter().map(|value| K::Native::usize_as(value.variant_index())));
+    Ok(DictionaryArray::new(keys, Arc::new(StringArray::from_iter_values(variants))))
+}
+
+/// Reads values of an [`EnumDebug`] type from a [`DictionaryArray`].
+///
+/// The dictionary does not have to be [`EnumDebug::variant_names()`] exactly; its names are
+/// matched to the variants of `T` by name (see [`EnumDebug::variant_index_of()`]). Hence,
+/// reordering or adding variants does not break columns that were written before.
+///
+/// # Arguments
+/// - `array`: The [`DictionaryArray`] to read.
+///
+/// # Returns
+/// The values in the array, where nulls are [`None`].
+///
+/// # Errors
+/// This function errors if the dictionary does not contain strings, or if a row refers to a name
+/// that is not a variant of `T`.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use arrow_array::types::Int8Type;
+/// use arrow_array::{DictionaryArray, Int8Array, StringArray};
+/// use enum_debug::arrow::{from_dictionary_array, DictionaryError};
+/// use enum_debug::{EnumDebug, UnknownVariantError};
+///
+/// #[derive(Debug, EnumDebug, PartialEq)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// // Written by a version of `Jedi` with a different order and another variant
+/// let dict = Arc::new(StringArray::from(vec!["MaceWindu", "AnakinSkywalker", "ObiWanKenobi"]));
+/// let array = DictionaryArray::<Int8Type>::new(Int8Array::from(vec![Some(2), None, Some(1)]), dict.clone());
+/// assert_eq!(from_dictionary_array::<_, Jedi>(&array).unwrap(), [Some(Jedi::ObiWanKenobi), None, Some(Jedi::AnakinSkywalker)]);
+///
+/// let array = DictionaryArray::<Int8Type>::new(Int8Array::from(vec![1, 0]), dict);
+/// match from_dictionary_array::<_, Jedi>(&array) {
+///     Err(DictionaryError::UnknownVariant { err }) => assert_eq!(err, UnknownVariantError::new::<Jedi>("MaceWindu")),
+///     res => panic!("Unexpected result: {res:?}"),
+/// }
+/// ```
+pub fn from_dictionary_array<K: ArrowDictionaryKeyType, T: FromVariant>(array: &DictionaryArray<K>) -> Result<Vec<Option<T>>, DictionaryError> {
+    let dict: &StringArray = match array.values().as_any().downcast_ref::<StringArray>() {
+        Some(dict) => dict,
+        None => return Err(DictionaryError::IllegalDictionary { data_type: array.values().data_type().clone() }),
+    };
+
+    // Map the dictionary to variant indices once, then every row is a lookup
+    let indices: Vec<Option<usize>> = dict.iter().map(|name| name.and_then(T::variant_index_of)).collect();
+    let mut res: Vec<Option<T>> = Vec::with_capacity(array.len());
+    for (i, key) in array.keys_iter().enumerate() {
+        let key: usize = match key {
+            Some(key) if array.is_valid(i) && dict.is_valid(key) => key,
+            _ => {
+                res.push(None);
+                continue;
+            },
+        };
+        match indices[key].and_then(T::from_variant_index) {
+            Some(value) => res.push(Some(value)),
+            None => return Err(DictionaryError::UnknownVariant { err: UnknownVariantError::new::<T>(dict.value(key)) }),
+        }
+    }
+    Ok(res)
+}
@@ -0,0 +1,212 @@
+//  ASYNC GRAPHQL.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:37:50
+//  Last edited:
+//    19 Oct 2026, 01:45:32
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `async-graphql` for [`EnumDebug`] types.
+//!
+//!   Fieldless enums can be used as GraphQL enums (both as input and as
+//!   output) through the [`EnumDebug`](derive@crate::EnumDebug)-derive macro
+//!   by adding `#[enum_debug(graphql)]`. This keeps the GraphQL values in sync
+//!   with the Rust enum:
+//!   - The GraphQL type is named after [`EnumDebug::type_name()`];
+//!   - the GraphQL values are [`EnumDebug::variant_names()`] in SCREAMING_SNAKE_CASE, where
+//!     words are split at changes from lowercase (or digits) to uppercase, before the last
+//!     uppercase letter of an acronym, and at `-`, `_` and spaces (e.g., `HTTPServer` becomes
+//!     `HTTP_SERVER`);
+//!   - the doc comments of the enum and its variants become their descriptions; and
+//!   - variants marked with `#[enum_debug(deprecated)]` or
+//!     `#[enum_debug(deprecated = "reason")]` are deprecated in the schema.
+//!
+//!   ```rust
+//!   use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//!   use enum_debug::EnumDebug;
+//!
+//!   /// The order of the Jedi.
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(graphql)]
+//!   enum Jedi {
+//!       /// The master of Anakin Skywalker.
+//!       ObiWanKenobi,
+//!       #[enum_debug(deprecated = "Turned to the dark side")]
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   struct Query;
+//!   #[Object]
+//!   impl Query {
+//!       async fn master(&self, padawan: Jedi) -> Option<Jedi> {
+//!           match padawan {
+//!               Jedi::AnakinSkywalker => Some(Jedi::ObiWanKenobi),
+//!               Jedi::ObiWanKenobi => None,
+//!           }
+//!       }
+//!   }
+//!
+//!   let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//!   assert!(schema.sdl().replace('\t', "    ").contains(
+//!       r#""""
+//!   The order of the Jedi.
+//!   """
+//!   enum Jedi {
+//!       """
+//!       The master of Anakin Skywalker.
+//!       """
+//!       OBI_WAN_KENOBI
+//!       ANAKIN_SKYWALKER @deprecated(reason: "Turned to the dark side")
+//!   }"#
+//!   ));
+//!
+//!   let res = pollster::block_on(schema.execute("{ master(padawan: ANAKIN_SKYWALKER) }"));
+//!   assert_eq!(res.data.to_string(), "{master: OBI_WAN_KENOBI}");
+//!   let res = pollster::block_on(schema.execute("{ master(padawan: DARTH_VADER) }"));
+//!   assert_eq!(
+//!       res.errors[0].message,
+//!       r#"Invalid value for argument "padawan", enumeration type "Jedi" does not contain the value "DARTH_VADER""#
+//!   );
+//!   ```
+//!
+//!   The names are computed by the derive macro, which rejects type names and
+//!   values that are not valid GraphQL names (including type names that are
+//!   paths, see `enum_debug(path)`) and variants that map to the same value:
+//!   ```compile_fail
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(graphql)]
+//!   enum Server {
+//!       HTTPServer,
+//!       HttpServer,
+//!   }
+//!   ```
+//!
+//!   Note that the derived implementation does not support the `boxed-trait`-
+//!   feature of `async-graphql`.
+//!
+//!   This module is only available when the `async-graphql`-feature is used.
+//
+
+use std::borrow::Cow;
+
+use ::async_graphql::indexmap::IndexMap;
+pub use ::async_graphql::registry::Deprecation;
+use ::async_graphql::registry::{MetaEnumValue, MetaType, MetaTypeId, Registry};
+use ::async_graphql::{InputType, InputValueError, InputValueResult, Name, Value};
+
+use crate::{EnumDebug, FromVariant};
+
+
+/***** LIBRARY *****/
+/// Returns the GraphQL type name of an [`EnumDebug`] type.
+///
+/// Used by the implementation derived with `#[enum_debug(graphql)]`.
+///
+/// # Returns
+/// [`EnumDebug::type_name()`].
+#[inline]
+pub fn type_name<T: EnumDebug>() -> Cow<'static, str> { Cow::Borrowed(T::type_name()) }
+
+/// Registers an [`EnumDebug`] type as a GraphQL enum.
+///
+/// Used by the implementation derived with `#[enum_debug(graphql)]`.
+///
+/// # Arguments
+/// - `registry`: The [`Registry`] to register the type in.
+/// - `description`: The description of the type itself, if any.
+/// - `values`: The names of the GraphQL values of the variants, in order.
+/// - `deprecation`: A function that returns the [`Deprecation`] of the variant at the given
+///   index.
+///
+/// # Returns
+/// The qualified name of the registered type.
+pub fn create_type_info<T: FromVariant + InputType>(
+    registry: &mut Registry,
+    description: Option<&str>,
+    values: &[&str],
+    deprecation: impl Fn(usize) -> Deprecation,
+) -> String {
+    registry.create_input_type::<T, _>(MetaTypeId::Enum, |_| {
+        let mut enum_values: IndexMap<String, MetaEnumValue> = IndexMap::with_capacity(values.len());
+        for (i, name) in values.iter().enumerate() {
+            enum_values.insert((*name).into(), MetaEnumValue {
+                name: (*name).into(),
+                description: T::variant_description(i).map(String::from),
+                deprecation: deprecation(i),
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                directive_invocations: Vec::new(),
+            });
+        }
+        MetaType::Enum {
+            name: <T as EnumDebug>::type_name().into(),
+            description: description.map(String::from),
+            enum_values,
+            visible: None,
+            inaccessible: false,
+            tags: Vec::new(),
+            rust_typename: Some(std::any::type_name::<T>()),
+            directive_invocations: Vec::new(),
+            requires_scopes: Vec::new(),
+        }
+    })
+}
+
+/// Parses a GraphQL enum value as a variant of an [`EnumDebug`] type.
+///
+/// Used by the implementation derived with `#[enum_debug(graphql)]`.
+///
+/// # Arguments
+/// - `value`: The [`Value`] to parse. Both enum values and strings are accepted.
+/// - `values`: The names of the GraphQL values of the variants, in order.
+///
+/// # Returns
+/// The variant whose name in `values` matches the given value.
+///
+/// # Errors
+/// This function errors if the value is not an enum value or a string, or if it does not match
+/// any variant. In the latter case, the error lists the values that would have matched.
+pub fn parse<T: FromVariant + InputType>(value: Option<Value>, values: &[&str]) -> InputValueResult<T> {
+    let value: Value = value.unwrap_or_default();
+    let name: &str = match &value {
+        Value::Enum(name) => name.as_str(),
+        Value::String(name) => name.as_str(),
+        _ => return Err(InputValueError::expected_type(value)),
+    };
+    match values.iter().position(|value| *value == name).and_then(T::from_variant_index) {
+        Some(res) => Ok(res),
+        None => Err(InputValueError::custom(format_args!(
+            "Enumeration type \"{}\" does not contain value \"{}\" (expected one of: {})",
+            <T as EnumDebug>::type_name(),
+            name,
+            values.join(", ")
+        ))),
+    }
+}
+
+/// Converts a variant of an [`EnumDebug`] type to a GraphQL enum value.
+///
+/// Used by the implementation derived with `#[enum_debug(graphql)]`.
+///
+/// # Arguments
+/// - `value`: The value to convert.
+/// - `values`: The names of the GraphQL values of the variants, in order.
+///
+/// # Returns
+/// A [`Value::Enum`] with the name in `values` of the variant.
+#[inline]
+pub fn to_value<T: EnumDebug>(value: &T, values: &[&str]) -> Value { Value::Enum(Name::new(values[value.variant_index()])) }
+
+
+
+/// Re-exports used by the implementation derived with `#[enum_debug(graphql)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::async_graphql;
+}
@@ -0,0 +1,384 @@
+//  BINARY.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:03:25
+//  Last edited:
+//    19 Oct 2026, 00:04:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a compact binary encoding for streams of [`EnumDebug`] values.
+//!
+//!   A stream starts with a header that contains the
+//!   [`EnumDebug::type_name()`] and [`EnumDebug::variant_names()`] of the
+//!   writer. Then, every value is written as its variant index as an unsigned
+//!   LEB128 varint; i.e., as a single byte for types with up to 128 variants.
+//!
+//!   Because the header carries the names, the reader does not have to use
+//!   the same version of the enum as the writer. The [`BinaryDecoder`] maps
+//!   the indices of the writer to its own variants by name, so variants can be
+//!   reordered and added freely. Only values of variants that the reader does
+//!   not know fail to decode:
+//!   ```rust
+//!   use enum_debug::binary::{BinaryDecoder, BinaryEncoder, DecodeError};
+//!   use enum_debug::{EnumDebug, UnknownVariantError};
+//!
+//!   mod v1 {
+//!       #[derive(Debug, enum_debug::EnumDebug, PartialEq)]
+//!       pub enum Jedi {
+//!           ObiWanKenobi,
+//!           AnakinSkywalker,
+//!       }
+//!   }
+//!   mod v2 {
+//!       #[derive(Debug, enum_debug::EnumDebug, PartialEq)]
+//!       pub enum Jedi {
+//!           MaceWindu,
+//!           AnakinSkywalker,
+//!           ObiWanKenobi,
+//!       }
+//!   }
+//!
+//!   // Newer binaries can read what older binaries wrote...
+//!   let mut encoder = BinaryEncoder::<_, v1::Jedi>::new(Vec::new()).unwrap();
+//!   encoder.encode(&v1::Jedi::AnakinSkywalker).unwrap();
+//!   encoder.encode(&v1::Jedi::ObiWanKenobi).unwrap();
+//!   let bytes: Vec<u8> = encoder.into_inner();
+//!   let decoded: Vec<v2::Jedi> = BinaryDecoder::new(&bytes[..]).unwrap().collect::<Result<_, _>>().unwrap();
+//!   assert_eq!(decoded, [v2::Jedi::AnakinSkywalker, v2::Jedi::ObiWanKenobi]);
+//!
+//!   // ...and vice versa, as long as they don't encounter new variants
+//!   let mut encoder = BinaryEncoder::<_, v2::Jedi>::new(Vec::new()).unwrap();
+//!   encoder.encode(&v2::Jedi::ObiWanKenobi).unwrap();
+//!   encoder.encode(&v2::Jedi::MaceWindu).unwrap();
+//!   let bytes: Vec<u8> = encoder.into_inner();
+//!   let mut decoder = BinaryDecoder::<_, v1::Jedi>::new(&bytes[..]).unwrap();
+//!   assert_eq!(decoder.decode().unwrap(), Some(v1::Jedi::ObiWanKenobi));
+//!   match decoder.decode() {
+//!       Err(DecodeError::UnknownVariant { err }) => assert_eq!(err, UnknownVariantError::new::<v1::Jedi>("MaceWindu")),
+//!       res => panic!("Unexpected result: {res:?}"),
+//!   }
+//!   ```
+//!
+//!   The layout of a stream is as follows, where every number is an unsigned
+//!   LEB128 varint and every string is its length in bytes followed by its
+//!   UTF-8 encoding:
+//!   ```plain
+//!   <type name> <number of variants> <variant name>... <variant index>...
+//!   ```
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::string::FromUtf8Error;
+
+use crate::{EnumDebug, FromVariant, UnknownVariantError};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when decoding a stream of [`EnumDebug`] values.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// Failed to read from the underlying reader (including when the stream ends halfway).
+    Read { err: io::Error },
+    /// A varint does not fit in 64 bits.
+    VarintOverflow,
+    /// A name in the header is not valid UTF-8.
+    IllegalName { err: FromUtf8Error },
+    /// The stream was written for another type.
+    TypeNameMismatch { expected: &'static str, got: String },
+    /// A value refers to a variant index that is not in the header.
+    IllegalIndex { index: u64, variants: usize },
+    /// A value refers to a variant that the reader does not know.
+    UnknownVariant { err: UnknownVariantError },
+}
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use DecodeError::*;
+        match self {
+            Read { .. } => write!(f, "Failed to read from stream"),
+            VarintOverflow => write!(f, "Encountered a varint that does not fit in 64 bits"),
+            IllegalName { .. } => write!(f, "Encountered a name that is not valid UTF-8"),
+            TypeNameMismatch { expected, got } => write!(f, "Stream contains values of type {got}, expected {expected}"),
+            IllegalIndex { index, variants } => write!(f, "Encountered variant index {index}, but the stream only has {variants} variants"),
+            UnknownVariant { .. } => write!(f, "Encountered a variant that is not known"),
+        }
+    }
+}
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use DecodeError::*;
+        match self {
+            Read { err } => Some(err),
+            IllegalName { err } => Some(err),
+            UnknownVariant { err } => Some(err),
+            VarintOverflow | TypeNameMismatch { .. } | IllegalIndex { .. } => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes an unsigned LEB128 varint.
+///
+/// # Arguments
+/// - `writer`: The [`Write`]r to write to.
+/// - `value`: The value to write.
+///
+/// # Errors
+/// This function errors if we failed to write to `writer`.
+fn write_varint(writer: &mut impl Write, mut value: u64) -> io::Result<()> {
+    let mut buf: [u8; 10] = [0; 10];
+    let mut len: usize = 0;
+    loop {
+        let byte: u8 = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+    writer.write_all(&buf[..len])
+}
+
+/// Writes a string as its length followed by its bytes.
+///
+/// # Arguments
+/// - `writer`: The [`Write`]r to write to.
+/// - `value`: The string to write.
+///
+/// # Errors
+/// This function errors if we failed to write to `writer`.
+fn write_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write_varint(writer, value.len() as u64)?;
+    writer.write_all(value.as_bytes())
+}
+
+/// Reads an unsigned LEB128 varint.
+///
+/// # Arguments
+/// - `reader`: The [`Read`]er to read from.
+///
+/// # Returns
+/// The value read, or [`None`] if the reader was at its end before the first byte.
+///
+/// # Errors
+/// This function errors if we failed to read from `reader`, if it ends halfway the varint, or if
+/// the varint does not fit in 64 bits.
+fn read_varint(reader: &mut impl Read) -> Result<Option<u64>, DecodeError> {
+    let mut value: u64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let mut byte: [u8; 1] = [0];
+        match reader.read(&mut byte) {
+            Ok(0) if shift == 0 => return Ok(None),
+            Ok(0) => return Err(DecodeError::Read { err: io::ErrorKind::UnexpectedEof.into() }),
+            Ok(_) => {},
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(DecodeError::Read { err }),
+        }
+        let bits: u64 = (byte[0] & 0x7F) as u64;
+        if (shift == 63 && bits > 1) || shift > 63 {
+            return Err(DecodeError::VarintOverflow);
+        }
+        value |= bits << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(value));
+        }
+        shift += 7;
+    }
+}
+
+/// Reads a string as its length followed by its bytes.
+///
+/// # Arguments
+/// - `reader`: The [`Read`]er to read from.
+///
+/// # Returns
+/// The string read.
+///
+/// # Errors
+/// This function errors if we failed to read from `reader`, if it ends halfway the string, or if
+/// the string is not valid UTF-8.
+fn read_string(reader: &mut impl Read) -> Result<String, DecodeError> {
+    let len: u64 = read_varint(reader)?.ok_or(DecodeError::Read { err: io::ErrorKind::UnexpectedEof.into() })?;
+    // NOTE: Not allocating `len` upfront, as it may be garbage
+    let mut bytes: Vec<u8> = Vec::new();
+    let n: usize = reader.take(len).read_to_end(&mut bytes).map_err(|err| DecodeError::Read { err })?;
+    if (n as u64) < len {
+        return Err(DecodeError::Read { err: io::ErrorKind::UnexpectedEof.into() });
+    }
+    String::from_utf8(bytes).map_err(|err| DecodeError::IllegalName { err })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Writes a stream of [`EnumDebug`] values in a compact binary encoding.
+///
+/// See the [module-level documentation](self) for the format.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::binary::BinaryEncoder;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// let mut encoder = BinaryEncoder::new(Vec::new()).unwrap();
+/// for jedi in [Jedi::AnakinSkywalker, Jedi::ObiWanKenobi, Jedi::AnakinSkywalker] {
+///     encoder.encode(&jedi).unwrap();
+/// }
+/// assert_eq!(encoder.into_inner(), b"\x04Jedi\x02\x0cObiWanKenobi\x0fAnakinSkywalker\x01\x00\x01");
+/// ```
+#[derive(Debug)]
+pub struct BinaryEncoder<W, T: ?Sized> {
+    /// The writer to write to.
+    writer: W,
+    _t:     PhantomData<fn(&T)>,
+}
+impl<W: Write, T: ?Sized + EnumDebug> BinaryEncoder<W, T> {
+    /// Constructor for the BinaryEncoder that writes the header immediately.
+    ///
+    /// # Arguments
+    /// - `writer`: The [`Write`]r to write the stream to.
+    ///
+    /// # Returns
+    /// A new BinaryEncoder to which values can be [encoded](BinaryEncoder::encode()).
+    ///
+    /// # Errors
+    /// This function errors if we failed to write the header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        write_string(&mut writer, T::type_name())?;
+        write_varint(&mut writer, T::variant_names().len() as u64)?;
+        for name in T::variants() {
+            write_string(&mut writer, name)?;
+        }
+        Ok(Self { writer, _t: PhantomData })
+    }
+
+    /// Writes a value to the stream.
+    ///
+    /// # Arguments
+    /// - `value`: The value to write.
+    ///
+    /// # Errors
+    /// This function errors if we failed to write the value.
+    #[inline]
+    pub fn encode(&mut self, value: &T) -> io::Result<()> { write_varint(&mut self.writer, value.variant_index() as u64) }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Returns
+    /// The [`Write`]r given when constructing the BinaryEncoder.
+    #[inline]
+    pub fn into_inner(self) -> W { self.writer }
+}
+
+
+
+/// Reads a stream of [`EnumDebug`] values in a compact binary encoding.
+///
+/// Values are mapped from the variants of the writer to those of `T` by name (see
+/// [`EnumDebug::variant_index_of()`], so aliases are recognized too).
+///
+/// Besides using [`BinaryDecoder::decode()`], the decoder can be used as an [`Iterator`] over the
+/// values in the stream.
+///
+/// See the [module-level documentation](self) for the format and an example.
+#[derive(Debug)]
+pub struct BinaryDecoder<R, T> {
+    /// The reader to read from.
+    reader: R,
+    /// The names of the variants of the writer.
+    names:  Vec<String>,
+    /// Maps the variant indices of the writer to those of `T`, if `T` has them.
+    remap:  Vec<Option<usize>>,
+    _t:     PhantomData<fn() -> T>,
+}
+impl<R: Read, T: FromVariant> BinaryDecoder<R, T> {
+    /// Constructor for the BinaryDecoder that reads the header immediately.
+    ///
+    /// # Arguments
+    /// - `reader`: The [`Read`]er to read the stream from.
+    ///
+    /// # Returns
+    /// A new BinaryDecoder from which values can be [decoded](BinaryDecoder::decode()).
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the header, or if it was written for a type with
+    /// another [`EnumDebug::type_name()`].
+    pub fn new(mut reader: R) -> Result<Self, DecodeError> {
+        let type_name: String = read_string(&mut reader)?;
+        if type_name != T::type_name() {
+            return Err(DecodeError::TypeNameMismatch { expected: T::type_name(), got: type_name });
+        }
+        let n_names: u64 = read_varint(&mut reader)?.ok_or(DecodeError::Read { err: io::ErrorKind::UnexpectedEof.into() })?;
+        let mut names: Vec<String> = Vec::new();
+        for _ in 0..n_names {
+            names.push(read_string(&mut reader)?);
+        }
+        let remap: Vec<Option<usize>> = names.iter().map(|name| T::variant_index_of(name)).collect();
+        Ok(Self { reader, names, remap, _t: PhantomData })
+    }
+
+    /// Reads the next value from the stream.
+    ///
+    /// # Returns
+    /// The next value, or [`None`] if the stream has ended.
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the value, if its index is not in the header, or
+    /// if it is a variant that `T` does not have. In the latter case, the error carries the name of
+    /// the variant.
+    pub fn decode(&mut self) -> Result<Option<T>, DecodeError> {
+        let index: u64 = match read_varint(&mut self.reader)? {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let i: usize = match usize::try_from(index) {
+            Ok(i) if i < self.names.len() => i,
+            _ => return Err(DecodeError::IllegalIndex { index, variants: self.names.len() }),
+        };
+        match self.remap[i].and_then(T::from_variant_index) {
+            Some(value) => Ok(Some(value)),
+            None => Err(DecodeError::UnknownVariant { err: UnknownVariantError::new::<T>(self.names[i].clone()) }),
+        }
+    }
+
+    /// Returns the variant names of the writer, as found in the header.
+    ///
+    /// # Returns
+    /// The names in the order of the writer's variant indices.
+    #[inline]
+    pub fn names(&self) -> &[String] { &self.names }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Returns
+    /// The [`Read`]er given when constructing the BinaryDecoder.
+    #[inline]
+    pub fn into_inner(self) -> R { self.reader }
+}
+impl<R: Read, T: FromVariant> Iterator for BinaryDecoder<R, T> {
+    type Item = Result<T, DecodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.decode().transpose() }
+}
@@ -0,0 +1,113 @@
+//  CLAP.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:26:52
+//  Last edited:
+//    19 Oct 2026, 01:23:21
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `clap` for [`EnumDebug`] types.
+//!
+//!   Fieldless enums can implement clap's [`ValueEnum`] through the
+//!   [`EnumDebug`](derive@crate::EnumDebug)-derive macro by adding
+//!   `#[enum_debug(value_enum)]`. The possible values then follow
+//!   [`EnumDebug::variant_names()`], take their help from the variant's doc
+//!   comments, and respect variants marked with
+//!   `#[enum_debug(alias = "...")]` or `#[enum_debug(hidden)]`:
+//!   ```rust
+//!   use clap::{CommandFactory as _, Parser};
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(Clone, Debug, EnumDebug, PartialEq)]
+//!   #[enum_debug(value_enum)]
+//!   enum Mode {
+//!       /// Runs as fast as possible.
+//!       Fast,
+//!       /// Runs as carefully as possible.
+//!       #[enum_debug(alias = "careful")]
+//!       Safe,
+//!       #[enum_debug(hidden)]
+//!       Debug,
+//!   }
+//!
+//!   #[derive(Parser)]
+//!   struct Arguments {
+//!       #[clap(long, value_enum)]
+//!       mode: Mode,
+//!   }
+//!
+//!   let args = Arguments::parse_from(["app", "--mode", "careful"]);
+//!   assert_eq!(args.mode, Mode::Safe);
+//!   assert_eq!(args.mode.variant_name(), "Safe");
+//!
+//!   let err = Arguments::try_parse_from(["app", "--mode", "Slow"]).err().unwrap();
+//!   assert!(err.to_string().contains("[possible values: Fast, Safe]"));
+//!
+//!   let help = Arguments::command().render_long_help().to_string();
+//!   assert!(help.contains("Fast: Runs as fast as possible."));
+//!   assert!(!help.contains("Debug"));
+//!   ```
+//!
+//!   This module is only available when the `clap`-feature is used.
+//
+
+pub use ::clap::builder::{PossibleValue, PossibleValuesParser};
+pub use ::clap::ValueEnum;
+
+use crate::EnumDebug;
+
+
+/***** LIBRARY *****/
+/// Returns the names of the variants of an [`EnumDebug`] type as clap [`PossibleValue`]s.
+///
+/// Unlike deriving [`ValueEnum`], this works for any [`EnumDebug`] type, including enums whose
+/// variants carry values.
+///
+/// Variants that are [hidden](EnumDebug::variant_hidden()) are still accepted, but are not shown
+/// in help and error messages.
+///
+/// # Returns
+/// An iterator that yields a [`PossibleValue`] for every name in
+/// [`EnumDebug::variant_names()`].
+#[inline]
+pub fn possible_values<T: ?Sized + EnumDebug>() -> impl Iterator<Item = PossibleValue> {
+    T::variant_names().iter().enumerate().map(|(i, name)| PossibleValue::new(*name).hide(T::variant_hidden(i)))
+}
+
+/// Returns a clap value parser that accepts the names of the variants of an [`EnumDebug`] type.
+///
+/// Clap includes the possible values in its help and error messages.
+///
+/// # Returns
+/// A [`PossibleValuesParser`] that parses any name in [`EnumDebug::variant_names()`] as a
+/// [`String`].
+///
+/// # Example
+/// ```rust
+/// use clap::{Arg, Command};
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Event {
+///     Connect { id: u32 },
+///     Disconnect { id: u32 },
+///     #[enum_debug(hidden)]
+///     Ping,
+/// }
+///
+/// let cmd = Command::new("app").arg(Arg::new("kind").long("kind").value_parser(enum_debug::clap::name_parser::<Event>()));
+/// let matches = cmd.clone().get_matches_from(["app", "--kind", "Connect"]);
+/// assert_eq!(matches.get_one::<String>("kind").map(String::as_str), Some("Connect"));
+///
+/// let err = cmd.try_get_matches_from(["app", "--kind", "Reconnect"]).unwrap_err();
+/// assert!(err.to_string().contains("[possible values: Connect, Disconnect]"));
+///
+/// // Hidden variants are accepted nonetheless
+/// let matches = Command::new("app").arg(Arg::new("kind").long("kind").value_parser(enum_debug::clap::name_parser::<&Event>())).get_matches_from(["app", "--kind", "Ping"]);
+/// assert_eq!(matches.get_one::<String>("kind").map(String::as_str), Some("Ping"));
+/// ```
+#[inline]
+pub fn name_parser<T: ?Sized + EnumDebug>() -> PossibleValuesParser { PossibleValuesParser::new(possible_values::<T>()) }
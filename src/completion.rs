@@ -0,0 +1,424 @@
+//  COMPLETION.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:25:13
+//  Last edited:
+//    19 Oct 2026, 01:21:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a generator for shell completion scripts that complete the
+//!   arguments of flags taking the variant names of [`EnumDebug`] types.
+//!
+//!   Bash, zsh and fish are supported. Zsh and fish also show the
+//!   [descriptions](EnumDebug::variant_description()) of the variants, and
+//!   [hidden](EnumDebug::variant_hidden()) variants are never completed:
+//!   ```rust
+//!   use enum_debug::completion::{CompletionGenerator, Shell};
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Mode {
+//!       /// Runs everything.
+//!       Full,
+//!       Partial,
+//!       #[enum_debug(hidden)]
+//!       Debug,
+//!   }
+//!
+//!   let generator = CompletionGenerator::new("jedi").bind::<Mode>(["-m", "--mode"]);
+//!   assert_eq!(
+//!       generator.generate(Shell::Fish).unwrap(),
+//!       "\
+//!   ## Generated by enum-debug. Do not edit this file manually.
+//!
+//!   complete -c jedi -s m -l mode -x -a 'Full' -d 'Runs everything.'
+//!   complete -c jedi -s m -l mode -x -a 'Partial'
+//!   "
+//!   );
+//!
+//!   // Write the scripts once, e.g., in `build.rs`...
+//!   let path = std::env::temp_dir().join("enum-debug-completion-doctest.bash");
+//!   generator.write(Shell::Bash, &path).unwrap();
+//!   // ...and verify them in CI, e.g., in a test
+//!   generator.check(Shell::Bash, &path).unwrap();
+//!   assert!(generator.check(Shell::Zsh, &path).is_err());
+//!   # std::fs::remove_file(&path).unwrap();
+//!   ```
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::path::Path;
+
+use crate::generated::{check_generated, write_generated, GeneratedFileError, HEADER};
+use crate::EnumDebug;
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when generating completion scripts.
+#[derive(Debug)]
+pub enum CompletionError {
+    /// The name of the program cannot be completed by shells.
+    IllegalProgram { program: String },
+    /// A flag is not of the form `-f`, `--flag` or `-flag`.
+    IllegalFlag { flag: String },
+    /// A flag is bound to more than one type.
+    DuplicateFlag { flag: String },
+    /// A type is bound without any flags.
+    NoFlags { type_name: &'static str },
+    /// Failed to write the generated file, or to check an existing one.
+    File { err: GeneratedFileError },
+}
+impl Display for CompletionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CompletionError::*;
+        match self {
+            IllegalProgram { program } => write!(f, "Program name '{program}' may only contain ASCII letters, digits, '_', '-', '.' and '+'"),
+            IllegalFlag { flag } => write!(f, "Flag '{flag}' is not of the form '-f', '--flag' or '-flag'"),
+            DuplicateFlag { flag } => write!(f, "Flag '{flag}' is bound more than once"),
+            NoFlags { type_name } => write!(f, "Type '{type_name}' is bound without any flags"),
+            File { err } => write!(f, "{err}"),
+        }
+    }
+}
+impl Error for CompletionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use CompletionError::*;
+        match self {
+            File { err } => err.source(),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether the given string is a flag we can generate completions for.
+///
+/// # Arguments
+/// - `flag`: The flag to check.
+///
+/// # Returns
+/// True if `flag` is one or two dashes followed by ASCII letters, digits, `_` and `-`.
+fn is_flag(flag: &str) -> bool {
+    let name: &str = flag.strip_prefix("--").or_else(|| flag.strip_prefix('-')).unwrap_or("");
+    !name.is_empty() && !name.starts_with('-') && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Quotes a string for bash or zsh.
+///
+/// # Arguments
+/// - `out`: The [`String`] to write to.
+/// - `value`: The string to quote.
+fn write_sh_quoted(out: &mut String, value: &str) {
+    out.push('\'');
+    out.push_str(&value.replace('\'', "'\\''"));
+    out.push('\'');
+}
+
+/// Quotes a string for fish.
+///
+/// # Arguments
+/// - `out`: The [`String`] to write to.
+/// - `value`: The string to quote.
+fn write_fish_quoted(out: &mut String, value: &str) {
+    out.push('\'');
+    out.push_str(&value.replace('\\', "\\\\").replace('\'', "\\'"));
+    out.push('\'');
+}
+
+/// Escapes a string such that fish reads it as a single literal token.
+///
+/// This is needed for the arguments given to `complete -a`, which fish tokenizes again.
+///
+/// # Arguments
+/// - `value`: The string to escape.
+///
+/// # Returns
+/// The escaped string.
+fn fish_token(value: &str) -> String {
+    let mut res: String = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            ' ' | '$' | '\\' | '*' | '?' | '~' | '#' | '(' | ')' | '{' | '}' | '[' | ']' | '<' | '>' | '^' | '&' | '|' | ';' | '"' | '\'' | '%' => {
+                res.push('\\');
+                res.push(c);
+            },
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// The shells for which [`CompletionGenerator`] can generate scripts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Shell {
+    /// GNU Bash. Descriptions are not shown.
+    ///
+    /// Source the script, e.g., from `~/.bashrc` or by placing it in bash-completion's completion
+    /// directory.
+    Bash,
+    /// The Z shell.
+    ///
+    /// Place the script as `_<program>` in a directory in `$fpath`.
+    Zsh,
+    /// The friendly interactive shell.
+    ///
+    /// Place the script as `<program>.fish` in `~/.config/fish/completions`.
+    Fish,
+}
+
+/// A flag binding an [`EnumDebug`] type, as given to [`CompletionGenerator::bind()`].
+#[derive(Clone, Debug)]
+struct Binding {
+    /// The flags that take the variant names.
+    flags:     Vec<String>,
+    /// The type name of the type.
+    type_name: &'static str,
+    /// The names and descriptions of the variants to complete, i.e., those that aren't hidden.
+    values:    Vec<(&'static str, Option<&'static str>)>,
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Generates shell completion scripts that complete the arguments of flags taking the variant
+/// names of [`EnumDebug`] types.
+///
+/// The scripts only complete the arguments of the given flags; all other arguments are left to
+/// the shell's default completion (e.g., of file names).
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Clone, Debug)]
+pub struct CompletionGenerator {
+    /// The name of the program to complete.
+    program:  String,
+    /// The flags to complete, in the order in which they were added.
+    bindings: Vec<Binding>,
+}
+impl CompletionGenerator {
+    /// Constructor for the CompletionGenerator.
+    ///
+    /// # Arguments
+    /// - `program`: The name of the program (i.e., the command typed by users) to complete.
+    ///
+    /// # Returns
+    /// A new CompletionGenerator without any flags.
+    #[inline]
+    pub fn new(program: impl Into<String>) -> Self { Self { program: program.into(), bindings: Vec::new() } }
+
+    /// Adds flags that take the variant names of an [`EnumDebug`] type.
+    ///
+    /// Both `--flag value` and `--flag=value` are completed. Variants that are
+    /// [hidden](EnumDebug::variant_hidden()) are not.
+    ///
+    /// # Arguments
+    /// - `flags`: The flags to complete, e.g., `["-m", "--mode"]`.
+    ///
+    /// # Returns
+    /// The same CompletionGenerator, for chaining.
+    pub fn bind<T: ?Sized + EnumDebug>(mut self, flags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let values: Vec<(&'static str, Option<&'static str>)> = T::variant_names()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !T::variant_hidden(*i))
+            .map(|(i, name)| (*name, T::variant_description(i)))
+            .collect();
+        self.bindings.push(Binding { flags: flags.into_iter().map(Into::into).collect(), type_name: T::type_name(), values });
+        self
+    }
+
+
+
+    /// Generates the completion script for the given shell.
+    ///
+    /// # Arguments
+    /// - `shell`: The [`Shell`] to generate the script for.
+    ///
+    /// # Returns
+    /// The contents of the script.
+    ///
+    /// # Errors
+    /// This function errors if the program name contains characters other than ASCII letters,
+    /// digits, `_`, `-`, `.` and `+`, if a flag is not of the form `-f`, `--flag` or `-flag`, if a
+    /// flag is given more than once, or if a type is bound without any flags.
+    pub fn generate(&self, shell: Shell) -> Result<String, CompletionError> {
+        if self.program.is_empty() || !self.program.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')) {
+            return Err(CompletionError::IllegalProgram { program: self.program.clone() });
+        }
+        if let Some(binding) = self.bindings.iter().find(|binding| binding.flags.is_empty()) {
+            return Err(CompletionError::NoFlags { type_name: binding.type_name });
+        }
+        let mut seen: Vec<&str> = Vec::new();
+        for flag in self.bindings.iter().flat_map(|binding| &binding.flags) {
+            if !is_flag(flag) {
+                return Err(CompletionError::IllegalFlag { flag: flag.clone() });
+            }
+            if seen.contains(&flag.as_str()) {
+                return Err(CompletionError::DuplicateFlag { flag: flag.clone() });
+            }
+            seen.push(flag);
+        }
+
+        // The name of the function that completes the program
+        let func: String = format!("_{}", self.program.replace(|c: char| !c.is_ascii_alphanumeric(), "_"));
+        let mut res: String = String::new();
+        match shell {
+            Shell::Bash => {
+                writeln!(res, "# {HEADER}").unwrap();
+                writeln!(res, "\n{func}() {{").unwrap();
+                res.push_str("    local cur=\"${COMP_WORDS[COMP_CWORD]}\" prev=\"${COMP_WORDS[COMP_CWORD-1]}\" name\n");
+                res.push_str("    # Complete `--flag=value` too\n");
+                res.push_str("    if [[ \"$prev\" == \"=\" && $COMP_CWORD -ge 2 ]]; then\n");
+                res.push_str("        prev=\"${COMP_WORDS[COMP_CWORD-2]}\"\n");
+                res.push_str("    elif [[ \"$cur\" == \"=\" ]]; then\n");
+                res.push_str("        cur=\"\"\n");
+                res.push_str("    fi\n");
+                res.push_str("    COMPREPLY=()\n");
+                res.push_str("    case \"$prev\" in\n");
+                for binding in &self.bindings {
+                    writeln!(res, "        {})", binding.flags.join("|")).unwrap();
+                    res.push_str("            local names=(");
+                    for (i, (name, _)) in binding.values.iter().enumerate() {
+                        if i > 0 {
+                            res.push(' ');
+                        }
+                        write_sh_quoted(&mut res, name);
+                    }
+                    res.push_str(")\n");
+                    res.push_str("            for name in \"${names[@]}\"; do\n");
+                    res.push_str("                # Bash inserts completions verbatim, so escape them\n");
+                    res.push_str("                printf -v name '%q' \"$name\"\n");
+                    res.push_str("                [[ \"$name\" == \"$cur\"* ]] && COMPREPLY+=(\"$name\")\n");
+                    res.push_str("            done\n");
+                    res.push_str("            return 0\n");
+                    res.push_str("            ;;\n");
+                }
+                res.push_str("    esac\n");
+                res.push_str("    return 0\n");
+                res.push_str("}\n");
+                writeln!(res, "\ncomplete -o default -F {func} {}", self.program).unwrap();
+            },
+
+            Shell::Zsh => {
+                writeln!(res, "#compdef {}", self.program).unwrap();
+                writeln!(res, "# {HEADER}").unwrap();
+                writeln!(res, "\n{func}() {{").unwrap();
+                res.push_str("    local -a values\n");
+                res.push_str("    local flag=\"$words[CURRENT-1]\"\n");
+                res.push_str("    # Complete `--flag=value` too\n");
+                res.push_str("    if [[ \"$PREFIX\" == -*=* ]]; then\n");
+                res.push_str("        flag=\"${PREFIX%%=*}\"\n");
+                res.push_str("        compset -P '*='\n");
+                res.push_str("    fi\n");
+                res.push_str("    case \"$flag\" in\n");
+                for binding in &self.bindings {
+                    writeln!(res, "        ({})", binding.flags.join("|")).unwrap();
+                    res.push_str("            values=(\n");
+                    for (name, desc) in &binding.values {
+                        // Colons separate the name from the description, so escape those
+                        let mut value: String = name.replace('\\', "\\\\").replace(':', "\\:");
+                        if let Some(desc) = desc {
+                            value.push(':');
+                            value.push_str(desc);
+                        }
+                        res.push_str("                ");
+                        write_sh_quoted(&mut res, &value);
+                        res.push('\n');
+                    }
+                    res.push_str("            )\n");
+                    res.push_str("            _describe -t values ");
+                    write_sh_quoted(&mut res, binding.type_name);
+                    res.push_str(" values\n");
+                    res.push_str("            return\n");
+                    res.push_str("            ;;\n");
+                }
+                res.push_str("    esac\n");
+                res.push_str("    _default\n");
+                res.push_str("}\n");
+                writeln!(res, "\nif [ \"$funcstack[1]\" = \"{func}\" ]; then").unwrap();
+                writeln!(res, "    {func} \"$@\"").unwrap();
+                res.push_str("else\n");
+                writeln!(res, "    compdef {func} {}", self.program).unwrap();
+                res.push_str("fi\n");
+            },
+
+            Shell::Fish => {
+                writeln!(res, "# {HEADER}").unwrap();
+                if !self.bindings.is_empty() {
+                    res.push('\n');
+                }
+                for binding in &self.bindings {
+                    // Describe which flags to complete
+                    let mut flags: String = String::new();
+                    for flag in &binding.flags {
+                        match (flag.strip_prefix("--"), flag.strip_prefix('-')) {
+                            (Some(long), _) => write!(flags, " -l {long}").unwrap(),
+                            (None, Some(short)) if short.len() == 1 => write!(flags, " -s {short}").unwrap(),
+                            (None, Some(old)) => write!(flags, " -o {old}").unwrap(),
+                            // NOTE: We checked all flags start with a dash
+                            (None, None) => unreachable!(),
+                        }
+                    }
+
+                    // Then complete each value separately to give each its own description
+                    for (name, desc) in &binding.values {
+                        write!(res, "complete -c {}{flags} -x -a ", self.program).unwrap();
+                        write_fish_quoted(&mut res, &fish_token(name));
+                        if let Some(desc) = desc {
+                            res.push_str(" -d ");
+                            write_fish_quoted(&mut res, desc);
+                        }
+                        res.push('\n');
+                    }
+                }
+            },
+        }
+        Ok(res)
+    }
+
+    /// Generates the completion script for the given shell and writes it to the given path.
+    ///
+    /// # Arguments
+    /// - `shell`: The [`Shell`] to generate the script for.
+    /// - `path`: The path of the file to write. Any existing file is overwritten.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](CompletionGenerator::generate()) the
+    /// script or to write it.
+    pub fn write(&self, shell: Shell, path: impl AsRef<Path>) -> Result<(), CompletionError> {
+        let contents: String = self.generate(shell)?;
+        write_generated(path.as_ref(), &contents).map_err(|err| CompletionError::File { err })
+    }
+
+    /// Checks that the script at the given path is up-to-date.
+    ///
+    /// Use this in a test to fail when the committed script no longer matches the Rust types.
+    ///
+    /// # Arguments
+    /// - `shell`: The [`Shell`] the script is for.
+    /// - `path`: The path of the script to check.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](CompletionGenerator::generate()) the
+    /// script, to read the existing file, or if its contents differ from what would be generated.
+    pub fn check(&self, shell: Shell, path: impl AsRef<Path>) -> Result<(), CompletionError> {
+        let contents: String = self.generate(shell)?;
+        check_generated(path.as_ref(), &contents).map_err(|err| CompletionError::File { err })
+    }
+}
@@ -0,0 +1,412 @@
+//  COUNTER.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:19:42
+//  Last edited:
+//    18 Oct 2026, 23:20:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`VariantCounter`], which counts occurrences of variants
+//!   concurrently, and [`VariantHistogram`], which reports on such
+//!   counts.
+//
+
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::set::QualifiedName;
+use crate::EnumDebug;
+
+
+/***** HELPER FUNCTIONS *****/
+/// Computes the percentage that a count is of a total.
+///
+/// # Arguments
+/// - `count`: The count to compute the percentage of.
+/// - `total`: The total of all counts.
+///
+/// # Returns
+/// A percentage between 0 and 100, or 0 if `total` is 0.
+#[inline]
+fn percentage(count: u64, total: u64) -> f64 { if total > 0 { 100.0 * count as f64 / total as f64 } else { 0.0 } }
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Determines the order in which a [`VariantHistogram`] reports its variants.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HistogramOrder {
+    /// The order in which the variants are declared, i.e., as in [`EnumDebug::variant_names()`].
+    #[default]
+    Declaration,
+    /// Descending by count. Variants with equal counts are kept in declaration order.
+    Count,
+}
+
+/// Formats a [`VariantHistogram`] as an aligned text table.
+///
+/// This formatter is returned by [`VariantHistogram::table()`].
+pub struct TableFormatter<'h, T> {
+    /// The histogram to format.
+    hist:  &'h VariantHistogram<T>,
+    /// The order in which to write the variants.
+    order: HistogramOrder,
+}
+impl<'h, T: EnumDebug> Display for TableFormatter<'h, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let total: u64 = self.hist.total();
+
+        // Compute the column widths
+        let name_width: usize = T::variant_names().iter().map(|name| name.len()).chain(["Variant".len(), "Total".len()]).max().unwrap_or(0);
+        let count_width: usize = total.to_string().len().max("Count".len());
+
+        // Write the table
+        writeln!(f, "{:<name_width$}  {:>count_width$}  {:>7}", "Variant", "Count", "%")?;
+        for (name, count) in self.hist.iter_ordered(self.order) {
+            writeln!(f, "{:<name_width$}  {:>count_width$}  {:>6.2}%", name, count, percentage(count, total))?;
+        }
+        writeln!(f, "{:-<width$}", "", width = name_width + count_width + 11)?;
+        writeln!(f, "{:<name_width$}  {:>count_width$}  {:>6.2}%", "Total", total, if total > 0 { 100.0 } else { 0.0 })
+    }
+}
+
+/// Formats a [`VariantHistogram`] as CSV.
+///
+/// This formatter is returned by [`VariantHistogram::csv()`].
+pub struct CsvFormatter<'h, T> {
+    /// The histogram to format.
+    hist:  &'h VariantHistogram<T>,
+    /// The order in which to write the variants.
+    order: HistogramOrder,
+}
+impl<'h, T: EnumDebug> Display for CsvFormatter<'h, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let total: u64 = self.hist.total();
+        writeln!(f, "variant,count,percentage")?;
+        for (name, count) in self.hist.iter_ordered(self.order) {
+            // Quote the name if it would otherwise break the format
+            if name.contains([',', '"', '\n', '\r']) {
+                write!(f, "\"{}\"", name.replace('"', "\"\""))?;
+            } else {
+                write!(f, "{name}")?;
+            }
+            writeln!(f, ",{},{:.2}", count, percentage(count, total))?;
+        }
+        Ok(())
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Counts how often each variant of an [`EnumDebug`] type occurs.
+///
+/// The counts are kept in atomics, so a counter can be shared between threads (e.g., in a
+/// `static` or an [`Arc`](std::sync::Arc)) and updated through a shared reference.
+///
+/// Use [`VariantCounter::histogram()`] or [`VariantCounter::take_histogram()`] to obtain a
+/// [`VariantHistogram`] that reports on the counts.
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use enum_debug::{EnumDebug, VariantCounter};
+///
+/// #[derive(EnumDebug)]
+/// enum Message {
+///     Ping,
+///     Data(Vec<u8>),
+/// }
+///
+/// let counter: Arc<VariantCounter<Message>> = Arc::new(VariantCounter::new());
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let counter = counter.clone();
+///         thread::spawn(move || {
+///             counter.increment(&Message::Ping);
+///             counter.increment(&Message::Data(vec![]));
+///             counter.increment(&Message::Data(vec![42]));
+///         })
+///     })
+///     .collect();
+/// handles.into_iter().for_each(|h| h.join().unwrap());
+///
+/// assert_eq!(counter.get(&Message::Ping), 4);
+/// assert_eq!(counter.get(&Message::Data(vec![])), 8);
+///
+/// // Report the counts over the last period and start over
+/// let hist = counter.take_histogram();
+/// assert_eq!(hist.total(), 12);
+/// assert_eq!(counter.total(), 0);
+/// ```
+pub struct VariantCounter<T> {
+    /// One count per variant, where the `i`th count belongs to the `i`th variant.
+    counts: Box<[AtomicU64]>,
+    /// Remembers the type of which we count variants.
+    _t:     PhantomData<fn() -> T>,
+}
+
+impl<T: EnumDebug> Default for VariantCounter<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<T: EnumDebug> VariantCounter<T> {
+    /// Constructor for the VariantCounter that initializes all counts to zero.
+    ///
+    /// # Returns
+    /// A new VariantCounter.
+    #[inline]
+    pub fn new() -> Self { Self { counts: T::variant_names().iter().map(|_| AtomicU64::new(0)).collect(), _t: PhantomData } }
+
+
+
+    /// Counts one occurrence of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to count the variant.
+    #[inline]
+    pub fn increment(&self, value: &T) { self.add(value, 1) }
+
+    /// Counts multiple occurrences of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to count the variant.
+    /// - `n`: The number of occurrences to add.
+    #[inline]
+    pub fn add(&self, value: &T, n: u64) { self.counts[value.variant_index()].fetch_add(n, Ordering::Relaxed); }
+
+    /// Resets all counts to zero.
+    #[inline]
+    pub fn reset(&self) { self.counts.iter().for_each(|count| count.store(0, Ordering::Relaxed)); }
+
+
+
+    /// Returns the current count of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to get the variant's count.
+    ///
+    /// # Returns
+    /// The number of times the variant has been counted.
+    #[inline]
+    pub fn get(&self, value: &T) -> u64 { self.counts[value.variant_index()].load(Ordering::Relaxed) }
+
+    /// Returns the sum of the current counts of all variants.
+    #[inline]
+    pub fn total(&self) -> u64 { self.counts.iter().map(|count| count.load(Ordering::Relaxed)).sum() }
+
+    /// Returns a snapshot of the current counts.
+    ///
+    /// Note that updates happening concurrently to this call may or may not be included.
+    ///
+    /// # Returns
+    /// A [`VariantHistogram`] with the current counts.
+    #[inline]
+    pub fn histogram(&self) -> VariantHistogram<T> {
+        VariantHistogram { counts: self.counts.iter().map(|count| count.load(Ordering::Relaxed)).collect(), _t: PhantomData }
+    }
+
+    /// Returns a snapshot of the current counts and resets them to zero.
+    ///
+    /// Every variant is swapped out individually, so concurrent updates are either included in
+    /// the returned histogram or counted towards the next.
+    ///
+    /// # Returns
+    /// A [`VariantHistogram`] with the counts up to this call.
+    #[inline]
+    pub fn take_histogram(&self) -> VariantHistogram<T> {
+        VariantHistogram { counts: self.counts.iter().map(|count| count.swap(0, Ordering::Relaxed)).collect(), _t: PhantomData }
+    }
+}
+
+impl<T: EnumDebug> Debug for VariantCounter<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let names: &'static [&'static str] = T::variant_names();
+        f.debug_map()
+            .entries(self.counts.iter().enumerate().map(|(i, count)| (QualifiedName::<T>::new(names[i]), count.load(Ordering::Relaxed))))
+            .finish()
+    }
+}
+
+
+
+/// Reports how often each variant of an [`EnumDebug`] type occurred.
+///
+/// Histograms are obtained from a [`VariantCounter`] or by calling
+/// [`variant_histogram()`](crate::iter::VariantIteratorExt::variant_histogram()) on an iterator.
+///
+/// The [`Display`]-formatter writes the histogram as an aligned text table in declaration order.
+/// Use [`VariantHistogram::table()`] to choose another order, or [`VariantHistogram::csv()`] to
+/// write it as CSV instead.
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::counter::HistogramOrder;
+/// use enum_debug::prelude::*;
+///
+/// #[derive(EnumDebug)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+///     MaceWindu,
+/// }
+///
+/// let hist = [Jedi::MaceWindu, Jedi::ObiWanKenobi, Jedi::MaceWindu, Jedi::MaceWindu].iter().variant_histogram();
+/// assert_eq!(
+///     hist.table(HistogramOrder::Count).to_string(),
+///     "\
+/// Variant          Count        %
+/// MaceWindu            3   75.00%
+/// ObiWanKenobi         1   25.00%
+/// AnakinSkywalker      0    0.00%
+/// -------------------------------
+/// Total                4  100.00%
+/// "
+/// );
+/// assert_eq!(
+///     hist.csv(HistogramOrder::Declaration).to_string(),
+///     "\
+/// variant,count,percentage
+/// ObiWanKenobi,1,25.00
+/// AnakinSkywalker,0,0.00
+/// MaceWindu,3,75.00
+/// "
+/// );
+/// ```
+pub struct VariantHistogram<T> {
+    /// One count per variant, where the `i`th count belongs to the `i`th variant.
+    counts: Box<[u64]>,
+    /// Remembers the type of which we count variants.
+    _t:     PhantomData<fn() -> T>,
+}
+
+impl<T: EnumDebug> Default for VariantHistogram<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<T: EnumDebug> VariantHistogram<T> {
+    /// Constructor for the VariantHistogram that initializes all counts to zero.
+    ///
+    /// # Returns
+    /// A new, empty VariantHistogram.
+    #[inline]
+    pub fn new() -> Self { Self { counts: vec![0; T::variant_names().len()].into_boxed_slice(), _t: PhantomData } }
+
+    /// Counts one occurrence of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to count the variant.
+    #[inline]
+    pub fn increment(&mut self, value: &T) { self.counts[value.variant_index()] += 1; }
+
+
+
+    /// Returns the count of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to get the variant's count.
+    ///
+    /// # Returns
+    /// The number of times the variant occurred.
+    #[inline]
+    pub fn count(&self, value: &T) -> u64 { self.counts[value.variant_index()] }
+
+    /// Returns the count of the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant.
+    ///
+    /// # Returns
+    /// The number of times the variant occurred, or [`None`] if `name` is not a variant of `T`.
+    #[inline]
+    pub fn count_name(&self, name: &str) -> Option<u64> { T::variant_index_of(name).map(|index| self.counts[index]) }
+
+    /// Returns the sum of the counts of all variants.
+    #[inline]
+    pub fn total(&self) -> u64 { self.counts.iter().sum() }
+
+    /// Returns an iterator over the variants and their counts in declaration order.
+    ///
+    /// # Returns
+    /// An iterator that yields pairs of variant names and counts, including variants that did not
+    /// occur.
+    #[inline]
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&'static str, u64)> { T::variant_names().iter().copied().zip(self.counts.iter().copied()) }
+
+    /// Returns the variants and their counts in the given order.
+    ///
+    /// # Arguments
+    /// - `order`: The [`HistogramOrder`] in which to return the variants.
+    ///
+    /// # Returns
+    /// An iterator that yields pairs of variant names and counts, including variants that did not
+    /// occur.
+    pub fn iter_ordered(&self, order: HistogramOrder) -> impl '_ + Iterator<Item = (&'static str, u64)> {
+        let mut pairs: Vec<(&'static str, u64)> = self.iter().collect();
+        if order == HistogramOrder::Count {
+            // NOTE: Stable, so ties remain in declaration order
+            pairs.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+        }
+        pairs.into_iter()
+    }
+
+
+
+    /// Returns a formatter that writes this histogram as an aligned text table.
+    ///
+    /// # Arguments
+    /// - `order`: The [`HistogramOrder`] in which to write the variants.
+    ///
+    /// # Returns
+    /// A [`TableFormatter`] that implements [`Display`].
+    #[inline]
+    pub fn table(&self, order: HistogramOrder) -> TableFormatter<'_, T> { TableFormatter { hist: self, order } }
+
+    /// Returns a formatter that writes this histogram as CSV.
+    ///
+    /// The CSV has a header, followed by one row per variant with its name, count and percentage
+    /// of the total.
+    ///
+    /// # Arguments
+    /// - `order`: The [`HistogramOrder`] in which to write the variants.
+    ///
+    /// # Returns
+    /// A [`CsvFormatter`] that implements [`Display`].
+    #[inline]
+    pub fn csv(&self, order: HistogramOrder) -> CsvFormatter<'_, T> { CsvFormatter { hist: self, order } }
+}
+
+impl<T> Clone for VariantHistogram<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self { counts: self.counts.clone(), _t: PhantomData } }
+}
+impl<T: EnumDebug> Debug for VariantHistogram<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_map().entries(self.iter().map(|(name, count)| (QualifiedName::<T>::new(name), count))).finish()
+    }
+}
+impl<T: EnumDebug> Display for VariantHistogram<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { self.table(HistogramOrder::Declaration).fmt(f) }
+}
+
+impl<'a, T: EnumDebug> Extend<&'a T> for VariantHistogram<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for value in iter {
+            self.increment(value);
+        }
+    }
+}
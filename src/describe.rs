@@ -0,0 +1,295 @@
+//  DESCRIBE.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:22:36
+//  Last edited:
+//    19 Oct 2026, 01:23:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`describe()`], which renders an [`EnumDebug`] type as a
+//!   reference table of its variants.
+//!
+//!   Every row lists the [type name](EnumDebug::type_name()), the index and
+//!   [name](EnumDebug::variant_names()) of a variant, its
+//!   [description](EnumDebug::variant_description()) and the
+//!   [shape of its fields](EnumDebug::variant_shape()). Use this to generate
+//!   documentation or help texts instead of writing them by hand:
+//!   ```rust
+//!   use enum_debug::describe::{describe, DescribeFormat};
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Mode {
+//!       /// Runs everything.
+//!       Full,
+//!       /// Runs only the given stages.
+//!       Partial { stages: Vec<String> },
+//!   }
+//!
+//!   assert_eq!(
+//!       describe::<Mode>(DescribeFormat::Table).to_string(),
+//!       "\
+//!   Type  Index  Variant  Description                  Fields
+//!   -------------------------------------------------------------
+//!   Mode      0  Full     Runs everything.
+//!   Mode      1  Partial  Runs only the given stages.  { stages }
+//!   "
+//!   );
+//!   ```
+//
+
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::marker::PhantomData;
+
+use crate::EnumDebug;
+
+
+/***** CONSTANTS *****/
+/// The headers of the columns of the table.
+const HEADERS: [&str; 5] = ["Type", "Index", "Variant", "Description", "Fields"];
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Escapes a string for use in a Markdown table cell.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `value`: The string to escape.
+///
+/// # Errors
+/// This function errors if it failed to write to `f`.
+fn write_markdown(f: &mut Formatter<'_>, value: &str) -> FResult {
+    for c in value.chars() {
+        match c {
+            '|' | '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' => write!(f, "\\{c}")?,
+            '\n' | '\r' => write!(f, " ")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Escapes a string for use in HTML.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `value`: The string to escape.
+///
+/// # Errors
+/// This function errors if it failed to write to `f`.
+fn write_html(f: &mut Formatter<'_>, value: &str) -> FResult {
+    for c in value.chars() {
+        match c {
+            '&' => write!(f, "&amp;")?,
+            '<' => write!(f, "&lt;")?,
+            '>' => write!(f, "&gt;")?,
+            '"' => write!(f, "&quot;")?,
+            '\'' => write!(f, "&#39;")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    Ok(())
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Determines how [`describe()`] renders an [`EnumDebug`] type.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DescribeFormat {
+    /// A Markdown table (as supported by, e.g., GitHub and most wikis).
+    Markdown,
+    /// An HTML `<table>`.
+    Html,
+    /// An aligned plain-text table, for terminals.
+    #[default]
+    Table,
+}
+
+/// Renders an [`EnumDebug`] type as a reference table of its variants.
+///
+/// This formatter is returned by [`describe()`].
+pub struct Description<T: ?Sized> {
+    /// The format to render in.
+    format: DescribeFormat,
+    /// The type to render.
+    _t:     PhantomData<fn() -> T>,
+}
+impl<T: ?Sized + EnumDebug> Description<T> {
+    /// Collects the cells of every row of the table (excluding the header).
+    ///
+    /// # Returns
+    /// The type name, index, variant name, description and shape of every variant.
+    fn rows() -> impl Iterator<Item = [String; 5]> {
+        T::variant_names().iter().enumerate().map(|(i, name)| {
+            [
+                T::type_name().into(),
+                i.to_string(),
+                (*name).into(),
+                T::variant_description(i).unwrap_or_default().into(),
+                T::variant_shape(i).map(|shape| shape.to_string()).unwrap_or_default(),
+            ]
+        })
+    }
+}
+impl<T: ?Sized + EnumDebug> Display for Description<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self.format {
+            DescribeFormat::Markdown => {
+                writeln!(f, "| {} |", HEADERS.join(" | "))?;
+                writeln!(f, "| --- | ---: | --- | --- | --- |")?;
+                for [type_name, index, name, desc, shape] in Self::rows() {
+                    write!(f, "| ")?;
+                    write_markdown(f, &type_name)?;
+                    write!(f, " | {index} | ")?;
+                    write_markdown(f, &name)?;
+                    write!(f, " | ")?;
+                    write_markdown(f, &desc)?;
+                    if !shape.is_empty() {
+                        // NOTE: Shapes never contain backticks, so no need to escape
+                        write!(f, " | `{shape}` |")?;
+                    } else {
+                        write!(f, " | |")?;
+                    }
+                    writeln!(f)?;
+                }
+                Ok(())
+            },
+
+            DescribeFormat::Html => {
+                writeln!(f, "<table>")?;
+                writeln!(f, "  <thead>")?;
+                write!(f, "    <tr>")?;
+                for header in HEADERS {
+                    write!(f, "<th>{header}</th>")?;
+                }
+                writeln!(f, "</tr>")?;
+                writeln!(f, "  </thead>")?;
+                writeln!(f, "  <tbody>")?;
+                for [type_name, index, name, desc, shape] in Self::rows() {
+                    write!(f, "    <tr><td>")?;
+                    write_html(f, &type_name)?;
+                    write!(f, "</td><td>{index}</td><td>")?;
+                    write_html(f, &name)?;
+                    write!(f, "</td><td>")?;
+                    write_html(f, &desc)?;
+                    write!(f, "</td><td>")?;
+                    if !shape.is_empty() {
+                        write!(f, "<code>")?;
+                        write_html(f, &shape)?;
+                        write!(f, "</code>")?;
+                    }
+                    writeln!(f, "</td></tr>")?;
+                }
+                writeln!(f, "  </tbody>")?;
+                writeln!(f, "</table>")
+            },
+
+            DescribeFormat::Table => {
+                // Compute the column widths
+                let mut widths: [usize; 5] = HEADERS.map(|header| header.chars().count());
+                for row in Self::rows() {
+                    for (width, cell) in widths.iter_mut().zip(&row) {
+                        *width = (*width).max(cell.chars().count());
+                    }
+                }
+
+                // Write the table, trimming trailing whitespace of empty columns
+                let write_row = |f: &mut Formatter<'_>, row: [&str; 5]| -> FResult {
+                    let mut line: String = String::new();
+                    for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+                        if i > 0 {
+                            line.push_str("  ");
+                        }
+                        let pad: String = " ".repeat(width - cell.chars().count());
+                        if i == 1 {
+                            // Numbers are right-aligned
+                            line.push_str(&pad);
+                            line.push_str(cell);
+                        } else {
+                            line.push_str(cell);
+                            line.push_str(&pad);
+                        }
+                    }
+                    writeln!(f, "{}", line.trim_end())
+                };
+                write_row(f, HEADERS)?;
+                writeln!(f, "{:-<width$}", "", width = widths.iter().sum::<usize>() + 2 * (widths.len() - 1))?;
+                for row in Self::rows() {
+                    write_row(f, row.each_ref().map(String::as_str))?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Renders an [`EnumDebug`] type as a reference table of its variants.
+///
+/// The table has a row per variant, with columns for the [type name](EnumDebug::type_name()),
+/// the index and [name](EnumDebug::variant_names()) of the variant, its
+/// [description](EnumDebug::variant_description()) and the
+/// [shape of its fields](EnumDebug::variant_shape()). Unknown descriptions and shapes are left
+/// empty.
+///
+/// # Arguments
+/// - `format`: The [`DescribeFormat`] to render the table in.
+///
+/// # Returns
+/// A [`Description`] that implements [`Display`].
+///
+/// # Example
+/// ```rust
+/// use enum_debug::describe::{describe, DescribeFormat};
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// #[enum_debug(name = "Mode")]
+/// enum RunMode {
+///     /// Runs *everything*.
+///     Full,
+///     Stages(Vec<String>),
+/// }
+///
+/// assert_eq!(
+///     describe::<RunMode>(DescribeFormat::Markdown).to_string(),
+///     "\
+/// | Type | Index | Variant | Description | Fields |
+/// | --- | ---: | --- | --- | --- |
+/// | Mode | 0 | Full | Runs \\*everything\\*. | |
+/// | Mode | 1 | Stages |  | `(_)` |
+/// "
+/// );
+/// assert_eq!(
+///     describe::<RunMode>(DescribeFormat::Html).to_string(),
+///     "\
+/// <table>
+///   <thead>
+///     <tr><th>Type</th><th>Index</th><th>Variant</th><th>Description</th><th>Fields</th></tr>
+///   </thead>
+///   <tbody>
+///     <tr><td>Mode</td><td>0</td><td>Full</td><td>Runs *everything*.</td><td></td></tr>
+///     <tr><td>Mode</td><td>1</td><td>Stages</td><td></td><td><code>(_)</code></td></tr>
+///   </tbody>
+/// </table>
+/// "
+/// );
+///
+/// // References describe the same as the type they refer to
+/// assert_eq!(describe::<&RunMode>(DescribeFormat::Table).to_string(), describe::<RunMode>(DescribeFormat::Table).to_string());
+/// ```
+#[inline]
+pub fn describe<T: ?Sized + EnumDebug>(format: DescribeFormat) -> Description<T> { Description { format, _t: PhantomData } }
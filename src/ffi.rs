@@ -0,0 +1,364 @@
+//  FFI.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:47:17
+//  Last edited:
+//    19 Oct 2026, 01:40:25
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements exporting the variant names of [`EnumDebug`] types to C.
+//!
+//!   Enums can export their names through the
+//!   [`EnumDebug`](derive@crate::EnumDebug)-derive macro by adding
+//!   `#[enum_debug(c_api)]`. This implements [`VariantCStrs`] and defines two
+//!   `extern "C"` functions, named after the enum in snake_case (e.g.,
+//!   `http_server` for `HTTPServer`), or after the prefix given with
+//!   `#[enum_debug(c_api = "prefix")]`:
+//!   - `size_t <prefix>_variant_count(void)`, which returns the number of variants; and
+//!   - `const char *<prefix>_variant_name(size_t index)`, which returns the name of the variant
+//!     at the given index, or `NULL` if there is no such variant.
+//!
+//!   The index of a variant is its [`EnumDebug::variant_index()`], i.e., its
+//!   position in the declaration. For fieldless enums without explicit
+//!   discriminants, this equals the discriminant. The returned strings are
+//!   static and must not be freed.
+//!
+//!   ```rust
+//!   use std::ffi::CStr;
+//!
+//!   use enum_debug::ffi::VariantCStrs;
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(c_api)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   assert_eq!(Jedi::variant_cstrs(), [c"ObiWanKenobi", c"AnakinSkywalker"]);
+//!
+//!   // These are the functions called from C
+//!   extern "C" {
+//!       fn jedi_variant_count() -> usize;
+//!       fn jedi_variant_name(index: usize) -> *const std::ffi::c_char;
+//!   }
+//!   unsafe {
+//!       assert_eq!(jedi_variant_count(), 2);
+//!       assert_eq!(CStr::from_ptr(jedi_variant_name(1)), c"AnakinSkywalker");
+//!       assert!(jedi_variant_name(2).is_null());
+//!   }
+//!
+//!   // Acronyms are kept together in the prefix
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(c_api)]
+//!   enum HTTPServer {
+//!       Apache,
+//!   }
+//!   assert_eq!(HTTPServer::c_prefix(), "http_server");
+//!   ```
+//!
+//!   Prefixes must be valid C identifiers:
+//!   ```compile_fail
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(c_api = "my-jedi")]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!   }
+//!   ```
+//!
+//!   Use [`CHeaderGenerator`] to generate a C header that declares the
+//!   functions.
+//!
+//!   This module is only available when the `ffi`-feature is used.
+//
+
+use std::error::Error;
+use std::ffi::{c_char, CStr};
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::path::Path;
+use std::ptr;
+
+use crate::generated::{check_generated, is_identifier, write_generated, GeneratedFileError, HEADER};
+use crate::{screaming_snake_case, EnumDebug};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when generating a C header.
+#[derive(Debug)]
+pub enum CHeaderError {
+    /// The prefix of an [`EnumDebug`] type is not a valid C identifier.
+    IllegalPrefix { type_name: &'static str, prefix: &'static str },
+    /// Two types have the same prefix.
+    DuplicatePrefix { prefix: &'static str },
+    /// The enum constant of a variant is not a valid C identifier.
+    IllegalConstant { type_name: &'static str, name: String },
+    /// Two variants map to the same enum constant.
+    DuplicateConstant { type_name: &'static str, name: String },
+    /// Failed to write the generated file, or to check an existing one.
+    File { err: GeneratedFileError },
+}
+impl Display for CHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CHeaderError::*;
+        match self {
+            IllegalPrefix { type_name, prefix } => write!(f, "Prefix '{prefix}' of type {type_name} is not a valid C identifier"),
+            DuplicatePrefix { prefix } => write!(f, "Prefix '{prefix}' is given more than once"),
+            IllegalConstant { type_name, name } => write!(f, "Enum constant '{name}' of type {type_name} is not a valid C identifier"),
+            DuplicateConstant { type_name, name } => write!(f, "Enum constant '{name}' of type {type_name} occurs more than once"),
+            File { err } => write!(f, "{err}"),
+        }
+    }
+}
+impl Error for CHeaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use CHeaderError::*;
+        match self {
+            File { err } => err.source(),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes a name in a C comment, making sure it cannot terminate the comment.
+///
+/// # Arguments
+/// - `out`: The [`String`] to write to.
+/// - `name`: The name to write.
+fn write_comment(out: &mut String, name: &str) { out.push_str(&name.replace("*/", "* /")) }
+
+
+
+
+
+/***** LIBRARY *****/
+/// Exposes the variant names of an [`EnumDebug`] type as C strings.
+///
+/// Derive this with `#[enum_debug(c_api)]`, which also defines the `extern "C"` functions
+/// described in the [module-level documentation](self).
+pub trait VariantCStrs: EnumDebug {
+    /// Returns the prefix of the `extern "C"` functions of this type.
+    ///
+    /// # Returns
+    /// The prefix, e.g., `jedi` for `jedi_variant_name()`.
+    fn c_prefix() -> &'static str;
+
+    /// Returns the names of the variants of this type as C strings.
+    ///
+    /// # Returns
+    /// A static slice with the same names (and order) as [`EnumDebug::variant_names()`].
+    fn variant_cstrs() -> &'static [&'static CStr];
+}
+
+
+
+/// Returns the number of variants of a [`VariantCStrs`] type.
+///
+/// Used by the `<prefix>_variant_count()` function derived with `#[enum_debug(c_api)]`.
+///
+/// # Returns
+/// The length of [`VariantCStrs::variant_cstrs()`].
+#[inline]
+pub fn variant_count<T: VariantCStrs>() -> usize { T::variant_cstrs().len() }
+
+/// Returns a pointer to the name of a variant of a [`VariantCStrs`] type.
+///
+/// Used by the `<prefix>_variant_name()` function derived with `#[enum_debug(c_api)]`.
+///
+/// # Arguments
+/// - `index`: The index of the variant.
+///
+/// # Returns
+/// A pointer to the static, nul-terminated name of the variant, or a null pointer if `index` is
+/// out-of-bounds.
+#[inline]
+pub fn variant_name_ptr<T: VariantCStrs>(index: usize) -> *const c_char {
+    match T::variant_cstrs().get(index) {
+        Some(name) => name.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+
+
+/// Generates a C header that declares the functions exported by [`VariantCStrs`] types.
+///
+/// For every type, the header declares the `<prefix>_variant_count()` and
+/// `<prefix>_variant_name()` functions. It also defines an `enum <prefix>_variant` with the
+/// indices of the variants, named after the prefix and the variant in SCREAMING_SNAKE_CASE.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::ffi::CHeaderGenerator;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// #[enum_debug(c_api)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// assert_eq!(
+///     CHeaderGenerator::new("JEDI_H").add::<Jedi>().generate().unwrap(),
+///     r#"/* Generated by enum-debug. Do not edit this file manually. */
+/// #ifndef JEDI_H
+/// #define JEDI_H
+///
+/// #include <stddef.h>
+///
+/// #ifdef __cplusplus
+/// extern "C" {
+/// #endif
+///
+/// /* The variants of Jedi. */
+/// enum jedi_variant {
+///     JEDI_OBI_WAN_KENOBI = 0,
+///     JEDI_ANAKIN_SKYWALKER = 1,
+/// };
+///
+/// /* Returns the number of variants of Jedi. */
+/// size_t jedi_variant_count(void);
+/// /* Returns the name of the variant of Jedi at the given index, or NULL if there is none. */
+/// const char *jedi_variant_name(size_t index);
+///
+/// #ifdef __cplusplus
+/// }
+/// #endif
+///
+/// #endif
+/// "#
+/// );
+///
+/// // Variants must map to different enum constants
+/// #[derive(EnumDebug)]
+/// #[enum_debug(c_api)]
+/// enum Server {
+///     HTTPServer,
+///     HttpServer,
+/// }
+/// assert!(CHeaderGenerator::new("SERVER_H").add::<Server>().generate().is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CHeaderGenerator {
+    /// The name of the include guard macro.
+    guard: String,
+    /// The type name, prefix and variant names of the types to generate.
+    types: Vec<(&'static str, &'static str, &'static [&'static str])>,
+}
+impl CHeaderGenerator {
+    /// Constructor for the CHeaderGenerator.
+    ///
+    /// # Arguments
+    /// - `guard`: The name of the macro used as include guard, e.g., `JEDI_H`.
+    ///
+    /// # Returns
+    /// A new CHeaderGenerator without any types.
+    #[inline]
+    pub fn new(guard: impl Into<String>) -> Self { Self { guard: guard.into(), types: Vec::new() } }
+
+    /// Adds a [`VariantCStrs`] type to generate.
+    ///
+    /// Types are generated in the order in which they are added.
+    ///
+    /// # Returns
+    /// The same CHeaderGenerator, for chaining.
+    #[inline]
+    pub fn add<T: VariantCStrs>(mut self) -> Self {
+        self.types.push((T::type_name(), T::c_prefix(), T::variant_names()));
+        self
+    }
+
+
+
+    /// Generates the C header.
+    ///
+    /// # Returns
+    /// The contents of the header.
+    ///
+    /// # Errors
+    /// This function errors if the prefix of a type or the enum constant of a variant is not a
+    /// valid C identifier, or if two types have the same prefix or two variants the same enum
+    /// constant.
+    pub fn generate(&self) -> Result<String, CHeaderError> {
+        let mut res: String = format!("/* {HEADER} */\n");
+        writeln!(res, "#ifndef {}\n#define {}\n", self.guard, self.guard).unwrap();
+        res.push_str("#include <stddef.h>\n\n#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+        // NOTE: Enum constants share one namespace in C, so they must be unique across all types
+        let mut constants: Vec<String> = Vec::new();
+        for (i, (type_name, prefix, variants)) in self.types.iter().enumerate() {
+            if !is_identifier(prefix, &['_'], &['_']) {
+                return Err(CHeaderError::IllegalPrefix { type_name, prefix });
+            }
+            if self.types[..i].iter().any(|(_, other, _)| other == prefix) {
+                return Err(CHeaderError::DuplicatePrefix { prefix });
+            }
+
+            // Write the indices (C does not allow empty enums)
+            if !variants.is_empty() {
+                res.push_str("\n/* The variants of ");
+                write_comment(&mut res, type_name);
+                writeln!(res, ". */\nenum {prefix}_variant {{").unwrap();
+                let value_prefix: String = screaming_snake_case(prefix);
+                for (j, variant) in variants.iter().enumerate() {
+                    let name: String = format!("{value_prefix}_{}", screaming_snake_case(variant));
+                    if !is_identifier(&name, &['_'], &['_']) {
+                        return Err(CHeaderError::IllegalConstant { type_name, name });
+                    }
+                    if constants.contains(&name) {
+                        return Err(CHeaderError::DuplicateConstant { type_name, name });
+                    }
+                    writeln!(res, "    {name} = {j},").unwrap();
+                    constants.push(name);
+                }
+                res.push_str("};\n");
+            }
+
+            // Write the functions
+            res.push_str("\n/* Returns the number of variants of ");
+            write_comment(&mut res, type_name);
+            writeln!(res, ". */\nsize_t {prefix}_variant_count(void);").unwrap();
+            res.push_str("/* Returns the name of the variant of ");
+            write_comment(&mut res, type_name);
+            writeln!(res, " at the given index, or NULL if there is none. */\nconst char *{prefix}_variant_name(size_t index);").unwrap();
+        }
+        writeln!(res, "\n#ifdef __cplusplus\n}}\n#endif\n\n#endif").unwrap();
+        Ok(res)
+    }
+
+    /// Generates the C header and writes it to the given path.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to write. Any existing file is overwritten.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](CHeaderGenerator::generate()) the header
+    /// or to write it.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), CHeaderError> {
+        let contents: String = self.generate()?;
+        write_generated(path.as_ref(), &contents).map_err(|err| CHeaderError::File { err })
+    }
+
+    /// Checks that the header at the given path is up-to-date.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to check.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](CHeaderGenerator::generate()) the header,
+    /// to read the existing file, or if its contents differ from what would be generated.
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), CHeaderError> {
+        let contents: String = self.generate()?;
+        check_generated(path.as_ref(), &contents).map_err(|err| CHeaderError::File { err })
+    }
+}
@@ -0,0 +1,242 @@
+//  FINGERPRINT.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:05:31
+//  Last edited:
+//    19 Oct 2026, 00:07:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements fingerprints of [`EnumDebug`] types that change whenever
+//!   their type name or (the order of) their variant names change.
+//!
+//!   Processes that exchange variant indices (e.g., over shared memory) can
+//!   compare fingerprints upfront to make sure they agree on what the indices
+//!   mean. The [`EnumDebug`](derive@crate::EnumDebug)-derive macro emits the
+//!   fingerprint as a constant by implementing [`SchemaFingerprint`], and
+//!   [`compare()`] explains why two fingerprints differ:
+//!   ```rust
+//!   use enum_debug::fingerprint::{compare, SchemaFingerprint, VariantChange};
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   // What the other process sends us
+//!   let (fingerprint, type_name, names) = (Jedi::SCHEMA_FINGERPRINT, "Jedi", ["ObiWanKenobi", "AnakinSkywalker"]);
+//!   assert!(compare::<Jedi>(fingerprint, type_name, &names).is_ok());
+//!
+//!   // ...or, if it has a newer version
+//!   let names = ["AnakinSkywalker", "ObiWanKenobi", "MaceWindu"];
+//!   let fingerprint = enum_debug::fingerprint::fingerprint(type_name, &names);
+//!   let err = compare::<Jedi>(fingerprint, type_name, &names).unwrap_err();
+//!   assert_eq!(err.changes, [
+//!       VariantChange::Moved { name: "ObiWanKenobi".into(), ours: 0, theirs: 1 },
+//!       VariantChange::Moved { name: "AnakinSkywalker".into(), ours: 1, theirs: 0 },
+//!       VariantChange::Unexpected { name: "MaceWindu".into(), index: 2 },
+//!   ]);
+//!   assert_eq!(
+//!       err.to_string(),
+//!       format!(
+//!           "Fingerprint {fingerprint:#018x} does not match fingerprint {:#018x} of Jedi:
+//!     - variant 'ObiWanKenobi' is at index 1 instead of 0
+//!     - variant 'AnakinSkywalker' is at index 0 instead of 1
+//!     - variant 'MaceWindu' at index 2 is unexpected",
+//!           Jedi::SCHEMA_FINGERPRINT
+//!       )
+//!   );
+//!   ```
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::EnumDebug;
+
+
+/***** CONSTANTS *****/
+/// The offset basis of 64-bit FNV-1a.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// The prime of 64-bit FNV-1a.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+
+
+
+
+/***** ERRORS *****/
+/// Describes that a fingerprint does not match that of an [`EnumDebug`] type, and why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FingerprintMismatch {
+    /// The [type name](EnumDebug::type_name()) of our type.
+    pub type_name:       &'static str,
+    /// The fingerprint of our type.
+    pub ours:            u64,
+    /// The fingerprint of their type.
+    pub theirs:          u64,
+    /// The type name of their type, if it differs from ours.
+    pub their_type_name: Option<String>,
+    /// How their variant names differ from ours. Empty if only the type name differs, or if the
+    /// fingerprints differ while the names do not (e.g., because they were not computed with
+    /// [`fingerprint()`]).
+    pub changes:         Vec<VariantChange>,
+}
+impl Display for FingerprintMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "Fingerprint {:#018x} does not match fingerprint {:#018x} of {}", self.theirs, self.ours, self.type_name)?;
+        if self.their_type_name.is_none() && self.changes.is_empty() {
+            return write!(f, " (but the names do)");
+        }
+        write!(f, ":")?;
+        if let Some(name) = &self.their_type_name {
+            write!(f, "\n  - type name is '{name}' instead of '{}'", self.type_name)?;
+        }
+        for change in &self.changes {
+            write!(f, "\n  - {change}")?;
+        }
+        Ok(())
+    }
+}
+impl Error for FingerprintMismatch {}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Describes how one variant name differs between our and their version of an [`EnumDebug`] type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VariantChange {
+    /// We have a variant that they do not have.
+    Missing { name: String, index: usize },
+    /// They have a variant that we do not have.
+    Unexpected { name: String, index: usize },
+    /// We both have a variant, but at different indices.
+    Moved { name: String, ours: usize, theirs: usize },
+}
+impl Display for VariantChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use VariantChange::*;
+        match self {
+            Missing { name, index } => write!(f, "variant '{name}' at index {index} is missing"),
+            Unexpected { name, index } => write!(f, "variant '{name}' at index {index} is unexpected"),
+            Moved { name, ours, theirs } => write!(f, "variant '{name}' is at index {theirs} instead of {ours}"),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Provides the fingerprint of an [`EnumDebug`] type as a constant.
+///
+/// The [`EnumDebug`](derive@crate::EnumDebug)-derive macro implements this trait, unless
+/// `#[enum_debug(path)]` is used (as the type name is not known at compile time then). For other
+/// types, use [`fingerprint_of()`] instead.
+pub trait SchemaFingerprint: EnumDebug {
+    /// The [`fingerprint()`] of the [type name](EnumDebug::type_name()) and
+    /// [variant names](EnumDebug::variant_names()) of this type.
+    const SCHEMA_FINGERPRINT: u64;
+}
+
+
+
+/// Computes the fingerprint of a type name and its variant names.
+///
+/// The fingerprint is the 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the
+/// UTF-8 encoding of the type name and then every variant name in order, each followed by a
+/// `0xFF`-byte (which cannot occur in UTF-8). Hence, it changes when the type name changes, or
+/// when variants are added, removed, renamed or reordered. It does not depend on the platform or
+/// on the version of this crate.
+///
+/// # Arguments
+/// - `type_name`: The [type name](EnumDebug::type_name()) to fingerprint.
+/// - `variant_names`: The [variant names](EnumDebug::variant_names()) to fingerprint, in order.
+///
+/// # Returns
+/// The fingerprint.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::fingerprint::fingerprint;
+///
+/// assert_eq!(fingerprint("Jedi", &["ObiWanKenobi", "AnakinSkywalker"]), 0x4c298e397a740571);
+/// assert_ne!(fingerprint("Jedi", &["AnakinSkywalker", "ObiWanKenobi"]), 0x4c298e397a740571);
+/// ```
+pub const fn fingerprint(type_name: &str, variant_names: &[&str]) -> u64 {
+    /// Hashes the bytes of a string followed by `0xFF`.
+    const fn hash_str(mut hash: u64, value: &str) -> u64 {
+        let bytes: &[u8] = value.as_bytes();
+        let mut i: usize = 0;
+        while i < bytes.len() {
+            hash = (hash ^ bytes[i] as u64).wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        (hash ^ 0xFF).wrapping_mul(FNV_PRIME)
+    }
+
+    let mut hash: u64 = hash_str(FNV_OFFSET_BASIS, type_name);
+    let mut i: usize = 0;
+    while i < variant_names.len() {
+        hash = hash_str(hash, variant_names[i]);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the fingerprint of an [`EnumDebug`] type.
+///
+/// This is equal to [`SchemaFingerprint::SCHEMA_FINGERPRINT`] for types that implement it, but
+/// works for any [`EnumDebug`] type.
+///
+/// # Returns
+/// The [`fingerprint()`] of [`EnumDebug::type_name()`] and [`EnumDebug::variant_names()`].
+#[inline]
+pub fn fingerprint_of<T: ?Sized + EnumDebug>() -> u64 { fingerprint(T::type_name(), T::variant_names()) }
+
+/// Compares the fingerprint of another process' version of an [`EnumDebug`] type with ours.
+///
+/// # Arguments
+/// - `fingerprint`: Their fingerprint.
+/// - `type_name`: Their type name. Only used to explain a mismatch.
+/// - `variant_names`: Their variant names, in order. Only used to explain a mismatch.
+///
+/// # Errors
+/// This function errors if `fingerprint` is not the [`fingerprint_of()`] `T`. The error lists how
+/// their type name and variant names differ from ours.
+pub fn compare<T: ?Sized + EnumDebug>(fingerprint: u64, type_name: &str, variant_names: &[&str]) -> Result<(), FingerprintMismatch> {
+    let ours: u64 = fingerprint_of::<T>();
+    if fingerprint == ours {
+        return Ok(());
+    }
+
+    // Diff the names to explain why
+    let our_names: &'static [&'static str] = T::variant_names();
+    let mut changes: Vec<VariantChange> = Vec::new();
+    for (i, name) in our_names.iter().enumerate() {
+        match variant_names.iter().position(|other| other == name) {
+            Some(j) if i == j => {},
+            Some(j) => changes.push(VariantChange::Moved { name: (*name).into(), ours: i, theirs: j }),
+            None => changes.push(VariantChange::Missing { name: (*name).into(), index: i }),
+        }
+    }
+    for (j, name) in variant_names.iter().enumerate() {
+        if !our_names.contains(name) {
+            changes.push(VariantChange::Unexpected { name: (*name).into(), index: j });
+        }
+    }
+    Err(FingerprintMismatch {
+        type_name: T::type_name(),
+        ours,
+        theirs: fingerprint,
+        their_type_name: if type_name != T::type_name() { Some(type_name.into()) } else { None },
+        changes,
+    })
+}
@@ -0,0 +1,127 @@
+//  GENERATED.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 01:20:31
+//  Last edited:
+//    19 Oct 2026, 01:20:51
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the parts shared by the generators of files from
+//!   [`EnumDebug`](crate::EnumDebug) types (e.g., the
+//!   [`typescript`](crate::typescript)- and [`proto`](crate::proto)-modules):
+//!   the header of generated files, checking identifiers, and writing and
+//!   checking the generated files.
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+
+/***** CONSTANTS *****/
+/// The text of the comment at the top of every generated file.
+pub(crate) const HEADER: &str = "Generated by enum-debug. Do not edit this file manually.";
+
+
+
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when writing or checking a generated file.
+#[derive(Debug)]
+pub enum GeneratedFileError {
+    /// Failed to read an existing file.
+    Read { path: PathBuf, err: io::Error },
+    /// Failed to write the generated file.
+    Write { path: PathBuf, err: io::Error },
+    /// An existing file does not contain what would be generated.
+    Stale { path: PathBuf },
+}
+impl Display for GeneratedFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use GeneratedFileError::*;
+        match self {
+            Read { path, .. } => write!(f, "Failed to read file '{}'", path.display()),
+            Write { path, .. } => write!(f, "Failed to write file '{}'", path.display()),
+            Stale { path } => write!(f, "File '{}' is stale; regenerate it", path.display()),
+        }
+    }
+}
+impl Error for GeneratedFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use GeneratedFileError::*;
+        match self {
+            Read { err, .. } | Write { err, .. } => Some(err),
+            Stale { .. } => None,
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Checks whether the given string is an ASCII identifier.
+///
+/// # Arguments
+/// - `name`: The name to check.
+/// - `first`: The characters other than ASCII letters allowed at the start of the name.
+/// - `rest`: The characters other than ASCII letters and digits allowed in the rest of the name.
+///
+/// # Returns
+/// True if `name` is non-empty and consists of the allowed characters, or false otherwise.
+pub(crate) fn is_identifier(name: &str, first: &[char], rest: &[char]) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || first.contains(&c)) && chars.all(|c| c.is_ascii_alphanumeric() || rest.contains(&c))
+}
+
+/// Reads a previously generated file, if it exists.
+///
+/// # Arguments
+/// - `path`: The path of the file to read.
+///
+/// # Returns
+/// The contents of the file, or [`None`] if it does not exist.
+///
+/// # Errors
+/// This function errors if the file exists but could not be read.
+pub(crate) fn read_generated(path: &Path) -> Result<Option<String>, GeneratedFileError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(GeneratedFileError::Read { path: path.into(), err }),
+    }
+}
+
+/// Writes a generated file, overwriting any existing file.
+///
+/// # Arguments
+/// - `path`: The path of the file to write.
+/// - `contents`: The generated contents.
+///
+/// # Errors
+/// This function errors if the file could not be written.
+pub(crate) fn write_generated(path: &Path, contents: &str) -> Result<(), GeneratedFileError> {
+    fs::write(path, contents).map_err(|err| GeneratedFileError::Write { path: path.into(), err })
+}
+
+/// Checks that a generated file is up-to-date.
+///
+/// # Arguments
+/// - `path`: The path of the file to check.
+/// - `contents`: The contents that would be generated now.
+///
+/// # Errors
+/// This function errors if the file could not be read, or if it does not contain `contents`.
+pub(crate) fn check_generated(path: &Path, contents: &str) -> Result<(), GeneratedFileError> {
+    let existing: String = fs::read_to_string(path).map_err(|err| GeneratedFileError::Read { path: path.into(), err })?;
+    if existing != contents {
+        return Err(GeneratedFileError::Stale { path: path.into() });
+    }
+    Ok(())
+}
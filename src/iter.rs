@@ -0,0 +1,293 @@
+//  ITER.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:19:42
+//  Last edited:
+//    18 Oct 2026, 23:21:46
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements extensions on [`Iterator`]s over [`EnumDebug`] values.
+//
+
+use std::borrow::Borrow;
+
+use crate::counter::VariantHistogram;
+use crate::map::VariantMap;
+use crate::set::VariantSet;
+use crate::{EnumDebug, UnknownVariantError};
+
+
+/***** AUXILLARY *****/
+/// Filters an iterator to only the values of a particular variant.
+///
+/// This iterator is returned by [`VariantIteratorExt::filter_variant()`].
+#[derive(Clone, Debug)]
+pub struct FilterVariant<I> {
+    /// The iterator to filter.
+    iter:  I,
+    /// The index of the variant to keep.
+    index: usize,
+}
+impl<I> Iterator for FilterVariant<I>
+where
+    I: Iterator,
+    I::Item: EnumDebug,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index: usize = self.index;
+        self.iter.find(|value| value.variant_index() == index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.iter.size_hint().1) }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Extends [`Iterator`]s over [`EnumDebug`] values with variant-aware operations.
+///
+/// This trait is implemented for all iterators, and is part of the [`prelude`](crate::prelude).
+/// Note that references to [`EnumDebug`] values are themselves [`EnumDebug`], so these functions
+/// are available on iterators by reference as well.
+pub trait VariantIteratorExt: Iterator {
+    /// Counts how often each variant occurs in this iterator.
+    ///
+    /// # Returns
+    /// A [`VariantHistogram`] with the number of occurrences of every variant.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// let hist = vec![Jedi::ObiWanKenobi, Jedi::ObiWanKenobi].into_iter().variant_histogram();
+    /// assert_eq!(hist.count(&Jedi::ObiWanKenobi), 2);
+    /// assert_eq!(hist.count(&Jedi::AnakinSkywalker), 0);
+    /// ```
+    #[inline]
+    fn variant_histogram(self) -> VariantHistogram<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: EnumDebug,
+    {
+        let mut hist: VariantHistogram<Self::Item> = VariantHistogram::new();
+        for value in self {
+            hist.increment(&value);
+        }
+        hist
+    }
+
+    /// Counts how often each variant occurs in this iterator.
+    ///
+    /// Unlike [`VariantIteratorExt::variant_histogram()`], only variants that actually occur get a
+    /// count.
+    ///
+    /// # Returns
+    /// A [`VariantMap`] with the number of occurrences of every variant that occurred.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    ///     MaceWindu,
+    /// }
+    ///
+    /// let counts = [Jedi::MaceWindu, Jedi::ObiWanKenobi, Jedi::MaceWindu].iter().count_variants();
+    /// assert_eq!(counts.iter().collect::<Vec<_>>(), vec![("ObiWanKenobi", &1), ("MaceWindu", &2)]);
+    /// ```
+    #[inline]
+    fn count_variants(self) -> VariantMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: EnumDebug,
+    {
+        let mut counts: VariantMap<Self::Item, usize> = VariantMap::new();
+        for value in self {
+            *counts.entry(&value).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Groups the values in this iterator by their variant.
+    ///
+    /// # Returns
+    /// A [`VariantMap`] that maps every variant that occurred to the values of that variant, in
+    /// the order they were yielded. Iterating over it follows the declaration order of the
+    /// variants.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(Debug, EnumDebug, PartialEq)]
+    /// enum Event {
+    ///     Connect(u32),
+    ///     Message(&'static str),
+    ///     Disconnect(u32),
+    /// }
+    ///
+    /// let events = vec![Event::Message("Hello"), Event::Connect(1), Event::Message("there")];
+    /// let groups = events.into_iter().group_by_variant();
+    /// assert_eq!(groups.iter().collect::<Vec<_>>(), vec![
+    ///     ("Connect", &vec![Event::Connect(1)]),
+    ///     ("Message", &vec![Event::Message("Hello"), Event::Message("there")]),
+    /// ]);
+    /// ```
+    #[inline]
+    fn group_by_variant(self) -> VariantMap<Self::Item, Vec<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: EnumDebug,
+    {
+        let mut groups: VariantMap<Self::Item, Vec<Self::Item>> = VariantMap::new();
+        for value in self {
+            // NOTE: Get the index first, as the entry can't borrow `value` while we move it
+            let index: usize = value.variant_index();
+            groups.entry_index(index).or_default().push(value);
+        }
+        groups
+    }
+
+    /// Splits the values in this iterator on whether their variant is in the given set.
+    ///
+    /// # Arguments
+    /// - `set`: A [`VariantSet`] with the variants of which to collect the values in the first
+    ///   list.
+    ///
+    /// # Returns
+    /// A pair of the values with a variant in `set` and those without, each in the order they were
+    /// yielded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    /// use enum_debug::VariantSet;
+    ///
+    /// #[derive(Debug, EnumDebug, PartialEq)]
+    /// enum Error {
+    ///     Timeout,
+    ///     Io(&'static str),
+    ///     InvalidInput,
+    /// }
+    ///
+    /// let retryable: VariantSet<Error> = "Timeout, Io".parse().unwrap();
+    /// let (retry, fail) = vec![Error::InvalidInput, Error::Io("oops"), Error::Timeout].into_iter().partition_variants(&retryable);
+    /// assert_eq!(retry, vec![Error::Io("oops"), Error::Timeout]);
+    /// assert_eq!(fail, vec![Error::InvalidInput]);
+    /// ```
+    #[inline]
+    fn partition_variants<T>(self, set: &VariantSet<T>) -> (Vec<Self::Item>, Vec<Self::Item>)
+    where
+        Self: Sized,
+        Self::Item: Borrow<T>,
+        T: EnumDebug,
+    {
+        self.partition(|value| set.contains(value.borrow()))
+    }
+
+    /// Filters this iterator to only the values of the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant to keep.
+    ///
+    /// # Returns
+    /// A [`FilterVariant`] iterator that only yields values of the variant `name`.
+    ///
+    /// # Panics
+    /// This function panics if `name` is not a variant of the iterator's item type. Use
+    /// [`VariantIteratorExt::try_filter_variant()`] to handle this case gracefully.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(Debug, EnumDebug, PartialEq)]
+    /// enum Event {
+    ///     Connect(u32),
+    ///     Disconnect(u32),
+    /// }
+    ///
+    /// let events = [Event::Connect(1), Event::Disconnect(1), Event::Connect(2)];
+    /// assert_eq!(events.iter().filter_variant("Connect").collect::<Vec<_>>(), vec![&Event::Connect(1), &Event::Connect(2)]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Event {
+    ///     Connect(u32),
+    ///     Disconnect(u32),
+    /// }
+    ///
+    /// // Typos are caught immediately
+    /// [Event::Connect(1)].iter().filter_variant("Conect");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn filter_variant(self, name: &str) -> FilterVariant<Self>
+    where
+        Self: Sized,
+        Self::Item: EnumDebug,
+    {
+        match self.try_filter_variant(name) {
+            Ok(iter) => iter,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Filters this iterator to only the values of the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant to keep.
+    ///
+    /// # Returns
+    /// A [`FilterVariant`] iterator that only yields values of the variant `name`.
+    ///
+    /// # Errors
+    /// This function errors if `name` is not a variant of the iterator's item type.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::prelude::*;
+    ///
+    /// #[derive(Debug, EnumDebug)]
+    /// enum Event {
+    ///     Connect(u32),
+    ///     Disconnect(u32),
+    /// }
+    ///
+    /// let err = [Event::Connect(1)].iter().try_filter_variant("Conect").unwrap_err();
+    /// assert_eq!(err.to_string(), "Unknown variant 'Conect' for type Event (expected one of: 'Connect', 'Disconnect')");
+    /// ```
+    #[inline]
+    fn try_filter_variant(self, name: &str) -> Result<FilterVariant<Self>, UnknownVariantError>
+    where
+        Self: Sized,
+        Self::Item: EnumDebug,
+    {
+        match <Self::Item as EnumDebug>::variant_index_of(name) {
+            Some(index) => Ok(FilterVariant { iter: self, index }),
+            None => Err(UnknownVariantError::new::<Self::Item>(name)),
+        }
+    }
+}
+impl<I: Iterator> VariantIteratorExt for I {}
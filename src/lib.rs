@@ -4,7 +4,7 @@
 //  Created:
 //    10 Dec 2022, 11:37:39
 //  Last edited:
-//    23 Jul 2024, 00:08:55
+//    19 Oct 2026, 01:43:24
 //  Auto updated?
 //    Yes
 //
@@ -98,16 +98,63 @@
 //!   
 //
 
+// Declare modules
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+pub mod binary;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod completion;
+pub mod counter;
+pub mod describe;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fingerprint;
+mod generated;
+pub mod iter;
+#[cfg(feature = "log")]
+pub mod log;
+pub mod map;
+pub mod matcher;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+pub mod proto;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod set;
+pub mod snapshot;
+pub mod sql;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+pub mod typescript;
+
+// Imports
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FResult};
 use std::iter::Copied;
 
 #[cfg(feature = "derive")]
 pub use enum_debug_derive::EnumDebug;
+pub use counter::{VariantCounter, VariantHistogram};
+pub use generated::GeneratedFileError;
+pub use map::VariantMap;
+pub use set::VariantSet;
 
 
 /***** MODULES *****/
 /// Can be used to bring this library's prelude into scope.
 pub mod prelude {
+    pub use super::iter::VariantIteratorExt;
     pub use super::EnumDebug;
 }
 
@@ -162,10 +209,96 @@ pub mod prelude {
 /// }
 ///
 /// assert_eq!(
-///     &format!("{:?}", Jedi::ObiWanKenobi.variant()),
-///     "rust_out::main::_doctest_main_src_lib_rs_152_0::Jedi::ObiWanKenobi"
+///     format!("{:?}", Jedi::ObiWanKenobi.variant()),
+///     format!("{}::ObiWanKenobi", std::any::type_name::<Jedi>())
 /// );
 /// ```
+///
+/// Finally, if the enum is also (de)serialized with [serde](https://serde.rs), you can make the
+/// variant names follow serde's `rename_all`, `rename` and `alias` attributes. Then, the names
+/// reported by [`EnumDebug`] are the same as those on the wire, and aliases are accepted when
+/// looking up variants by name:
+/// ```
+/// use enum_debug::EnumDebug;
+/// use serde::Serialize;
+///
+/// #[derive(EnumDebug, Serialize)]
+/// #[enum_debug(follow_serde)]
+/// #[serde(rename_all = "snake_case")]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     #[serde(rename = "darth_vader", alias = "anakin")]
+///     AnakinSkywalker,
+///     MaceWindu,
+/// }
+///
+/// assert_eq!(Jedi::variant_names(), &["obi_wan_kenobi", "darth_vader", "mace_windu"]);
+/// assert_eq!(format!("{:?}", Jedi::AnakinSkywalker.variant()), "Jedi::darth_vader");
+/// assert_eq!(Jedi::variant_index_of("anakin"), Some(1));
+///
+/// // Raw identifiers are named without their `r#`, like serde does
+/// #[derive(EnumDebug, Serialize)]
+/// #[enum_debug(follow_serde)]
+/// #[allow(non_camel_case_types)]
+/// enum Keyword {
+///     r#type,
+/// }
+/// assert_eq!(Keyword::variant_names(), &["type"]);
+/// ```
+///
+/// Note that this only affects the variant names; the type name is not changed by serde's
+/// container-level `rename`. Variants with `#[serde(skip)]` are still named like serde would,
+/// as every variant needs a name. Two variants may not end up with the same name or alias:
+/// ```compile_fail
+/// use enum_debug::EnumDebug;
+/// use serde::Serialize;
+///
+/// #[derive(EnumDebug, Serialize)]
+/// #[enum_debug(follow_serde)]
+/// #[serde(rename_all = "lowercase")]
+/// enum Jedi {
+///     Yoda,
+///     #[serde(rename = "yoda")]
+///     MasterYoda,
+/// }
+/// ```
+///
+/// Variants can be given additional names by which they are found (e.g., in
+/// [`EnumDebug::variant_index_of()`]) using `alias`, and can be marked as `hidden`. The latter
+/// does not change their names, but is [reported](EnumDebug::variant_hidden()) to integrations
+/// such as `value_enum` (see the [`clap`](crate::clap)-module, only available with the
+/// `clap`-feature) and the [`completion`]-module:
+/// ```
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Jedi {
+///     #[enum_debug(alias = "Ben")]
+///     ObiWanKenobi,
+///     #[enum_debug(hidden)]
+///     AnakinSkywalker,
+/// }
+///
+/// assert_eq!(Jedi::variant_index_of("Ben"), Some(0));
+/// assert!(Jedi::variant_hidden(1));
+/// ```
+///
+/// Aliases may not be used by another variant, neither as its name nor as one of its aliases:
+/// ```compile_fail
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Mode {
+///     Fast,
+///     #[enum_debug(alias = "Fast")]
+///     Safe,
+/// }
+/// ```
+///
+/// Finally, the derive macro uses the first paragraph of a variant's doc comments as its
+/// [description](EnumDebug::variant_description()). Integrations such as `value_enum` and
+/// `json_schema` (see the [`schemars`]-module, only available with the
+/// `schemars`-feature) use these to document the variant names.
 #[cfg(feature = "derive")]
 pub mod derive {
     pub use enum_debug_derive::EnumDebug;
@@ -175,12 +308,117 @@ pub mod derive {
 
 
 
+/***** HELPER FUNCTIONS *****/
+/// Converts a name to snake_case or SCREAMING_SNAKE_CASE, keeping acronyms together.
+///
+/// Words are split at changes from lowercase (or digits) to uppercase, before the last uppercase
+/// letter of an acronym, and at the given separators. For example, `HTTPServer` becomes
+/// `http_server`.
+///
+/// The derive macro mirrors this conversion, as a proc-macro crate cannot share it.
+///
+/// # Arguments
+/// - `name`: The name to convert.
+/// - `separators`: The characters that separate words. They are replaced by a single `_`.
+/// - `upper`: Whether to convert to SCREAMING_SNAKE_CASE instead of snake_case.
+///
+/// # Returns
+/// The converted name.
+pub(crate) fn convert_case(name: &str, separators: &[char], upper: bool) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut res: String = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if separators.contains(c) {
+            if !res.is_empty() && !res.ends_with('_') {
+                res.push('_');
+            }
+            continue;
+        }
+        if c.is_uppercase() && i > 0 && !res.ends_with('_') {
+            let prev: char = chars[i - 1];
+            let next_lower: bool = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                res.push('_');
+            }
+        }
+        if upper {
+            res.extend(c.to_uppercase());
+        } else {
+            res.extend(c.to_lowercase());
+        }
+    }
+    res
+}
+
+/// Converts a name to SCREAMING_SNAKE_CASE for the file generators.
+///
+/// Besides `-`, `_` and spaces, this also splits at `:` to support type names that are paths.
+///
+/// # Arguments
+/// - `name`: The name to convert.
+///
+/// # Returns
+/// The name in SCREAMING_SNAKE_CASE.
+pub(crate) fn screaming_snake_case(name: &str) -> String { convert_case(name, &['-', '_', ':', ' '], true) }
+
+
+
+
+
+/***** ERRORS *****/
+/// Describes that a name did not match any of the variants of an [`EnumDebug`] type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownVariantError {
+    /// The [type name](EnumDebug::type_name()) of the enum that was searched.
+    pub type_name: &'static str,
+    /// The name that did not match any variant.
+    pub name:      String,
+    /// The [names](EnumDebug::variant_names()) of the variants that would have matched.
+    pub variants:  &'static [&'static str],
+}
+impl UnknownVariantError {
+    /// Constructor for the UnknownVariantError that takes the type information from `T`.
+    ///
+    /// # Arguments
+    /// - `name`: The name that did not match any variant of `T`.
+    ///
+    /// # Returns
+    /// A new UnknownVariantError that lists the variants of `T` as alternatives.
+    #[inline]
+    pub fn new<T: ?Sized + EnumDebug>(name: impl Into<String>) -> Self {
+        Self { type_name: T::type_name(), name: name.into(), variants: T::variant_names() }
+    }
+}
+impl Display for UnknownVariantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "Unknown variant '{}' for type {} ", self.name, self.type_name)?;
+        if self.variants.is_empty() {
+            write!(f, "(type has no variants)")
+        } else {
+            write!(f, "(expected one of: ")?;
+            for (i, variant) in self.variants.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "'{variant}'")?;
+            }
+            write!(f, ")")
+        }
+    }
+}
+impl Error for UnknownVariantError {}
+
+
+
+
+
 /***** AUXILLARY *****/
 /// Implements a formatter that can write the variant name of an enum.
 ///
 /// The [`Debug`]-formatter writes the enum name and its current variant name, as given by the [`EnumDebug`] trait.
 ///
-/// The [`Display`]-formatter just writes its name.
+/// The [`Display`]-formatter just writes its name, unless the formatter is made
+/// [qualified](EnumDebugFormatter::qualified()), in which case it writes the enum name as well.
 ///
 /// This formatter is returned by [`EnumDebug::variant()`].
 ///
@@ -189,13 +427,89 @@ pub mod derive {
 pub struct EnumDebugFormatter<'a, T: ?Sized> {
     /// The enum to format.
     e: &'a T,
+    /// Whether the [`Display`]-formatter writes the enum name too.
+    qualified: bool,
+}
+impl<'a, T: ?Sized> EnumDebugFormatter<'a, T> {
+    /// Makes this formatter also write the enum name in its [`Display`]-implementation.
+    ///
+    /// # Returns
+    /// The same formatter, but writing `Type::Variant` instead of `Variant`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// assert_eq!(Jedi::ObiWanKenobi.variant().qualified().to_string(), "Jedi::ObiWanKenobi");
+    /// ```
+    #[inline]
+    pub fn qualified(mut self) -> Self {
+        self.qualified = true;
+        self
+    }
+
+    /// Returns whether this formatter writes the enum name in its [`Display`]-implementation.
+    #[inline]
+    pub fn is_qualified(&self) -> bool { self.qualified }
 }
 impl<'a, T: EnumDebug> Debug for EnumDebugFormatter<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}::{}", T::type_name(), self.e.variant_name()) }
 }
 impl<'a, T: EnumDebug> Display for EnumDebugFormatter<'a, T> {
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.e.variant_name()) }
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        if self.qualified {
+            write!(f, "{}::{}", T::type_name(), self.e.variant_name())
+        } else {
+            write!(f, "{}", self.e.variant_name())
+        }
+    }
+}
+
+
+/// Describes the fields of a variant, as returned by [`EnumDebug::variant_shape()`].
+///
+/// Its [`Display`]-implementation writes the shape like a pattern that matches the variant, e.g.,
+/// `(_, _)` or `{ name, age }`, or nothing at all for unit variants.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::VariantShape;
+///
+/// assert_eq!(VariantShape::Unit.to_string(), "");
+/// assert_eq!(VariantShape::Tuple(2).to_string(), "(_, _)");
+/// assert_eq!(VariantShape::Struct(&["name", "age"]).to_string(), "{ name, age }");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VariantShape {
+    /// The variant has no fields, e.g., `Jedi::ObiWanKenobi`.
+    Unit,
+    /// The variant has the given number of unnamed fields, e.g., `Jedi::Padawan(String)`.
+    Tuple(usize),
+    /// The variant has fields with the given names, e.g., `Jedi::Master { name: String }`.
+    Struct(&'static [&'static str]),
+}
+impl Display for VariantShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Unit => Ok(()),
+            Self::Tuple(n) => {
+                write!(f, "(")?;
+                for i in 0..*n {
+                    write!(f, "{}_", if i > 0 { ", " } else { "" })?;
+                }
+                write!(f, ")")
+            },
+            Self::Struct([]) => write!(f, "{{}}"),
+            Self::Struct(fields) => write!(f, "{{ {} }}", fields.join(", ")),
+        }
+    }
 }
 
 
@@ -382,6 +696,215 @@ pub trait EnumDebug {
     /// ```
     fn variant_name(&self) -> &'static str;
 
+    /// Returns the index of the variant in [`Self::variant_names()`](EnumDebug::variant_names()).
+    ///
+    /// # Returns
+    /// A [`usize`] such that `Self::variant_names()[self.variant_index()] == self.variant_name()`.
+    ///
+    /// By default, this is found by searching [`Self::variant_names()`](EnumDebug::variant_names())
+    /// for the current variant's name. If you have derived this automatically, it is instead
+    /// resolved directly by matching on the variant.
+    ///
+    /// # Panics
+    /// The default implementation panics if [`Self::variant_name()`](EnumDebug::variant_name())
+    /// returns a name that does not occur in [`Self::variant_names()`](EnumDebug::variant_names()).
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::EnumDebug;
+    ///
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    ///     MaceWindu,
+    ///     MasterYoda,
+    /// }
+    /// impl EnumDebug for Jedi {
+    ///     // e.g. derived
+    /// #     // NOTE: Not necessary, but otherwise it will use the Rust internal type name
+    /// #     #[inline]
+    /// #     fn type_name() -> &'static str { "Jedi" }
+    /// #
+    /// #     #[inline]
+    /// #     fn variant_names() -> &'static [&'static str] {
+    /// #         &["ObiWanKenobi", "AnakinSkywalker", "MaceWindu", "MasterYoda"]
+    /// #     }
+    /// #
+    /// #     #[inline]
+    /// #     fn variant_name(&self) -> &'static str {
+    /// #         match self {
+    /// #             Self::ObiWanKenobi => Self::variant_names()[0],
+    /// #             Self::AnakinSkywalker => Self::variant_names()[1],
+    /// #             Self::MaceWindu => Self::variant_names()[2],
+    /// #             Self::MasterYoda => Self::variant_names()[3],
+    /// #         }
+    /// #     }
+    /// }
+    ///
+    /// assert_eq!(Jedi::ObiWanKenobi.variant_index(), 0);
+    /// assert_eq!(Jedi::MaceWindu.variant_index(), 2);
+    /// ```
+    #[inline]
+    fn variant_index(&self) -> usize {
+        let name: &'static str = self.variant_name();
+        match Self::variant_index_of(name) {
+            Some(index) => index,
+            None => panic!("Variant name '{}' is not in {}::variant_names()", name, Self::type_name()),
+        }
+    }
+
+    /// Returns the index of the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the variant to find. Should be unqualified, i.e., without the
+    ///   [type name](EnumDebug::type_name()) in front of it.
+    ///
+    /// # Returns
+    /// The index of `name` in [`Self::variant_names()`](EnumDebug::variant_names()), or [`None`]
+    /// if there is no variant by that name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::EnumDebug;
+    ///
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    ///     MaceWindu,
+    ///     MasterYoda,
+    /// }
+    /// impl EnumDebug for Jedi {
+    ///     // e.g. derived
+    /// #     // NOTE: Not necessary, but otherwise it will use the Rust internal type name
+    /// #     #[inline]
+    /// #     fn type_name() -> &'static str { "Jedi" }
+    /// #
+    /// #     #[inline]
+    /// #     fn variant_names() -> &'static [&'static str] {
+    /// #         &["ObiWanKenobi", "AnakinSkywalker", "MaceWindu", "MasterYoda"]
+    /// #     }
+    /// #
+    /// #     #[inline]
+    /// #     fn variant_name(&self) -> &'static str {
+    /// #         match self {
+    /// #             Self::ObiWanKenobi => Self::variant_names()[0],
+    /// #             Self::AnakinSkywalker => Self::variant_names()[1],
+    /// #             Self::MaceWindu => Self::variant_names()[2],
+    /// #             Self::MasterYoda => Self::variant_names()[3],
+    /// #         }
+    /// #     }
+    /// }
+    ///
+    /// assert_eq!(Jedi::variant_index_of("AnakinSkywalker"), Some(1));
+    /// assert_eq!(Jedi::variant_index_of("DarthVader"), None);
+    /// ```
+    #[inline]
+    fn variant_index_of(name: &str) -> Option<usize> { Self::variant_names().iter().position(|n| *n == name) }
+
+    /// Returns a description of the variant at the given index.
+    ///
+    /// Integrations use this to document the variant names they expose, e.g., in help texts or
+    /// schemas. If you derive this trait, the description is the first paragraph of the
+    /// variant's doc comments.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in
+    ///   [`Self::variant_names()`](EnumDebug::variant_names()).
+    ///
+    /// # Returns
+    /// The description of the variant, or [`None`] if it has none (or `index` is out-of-bounds).
+    /// By default, no variant has a description.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     /// The master of Anakin Skywalker.
+    ///     ///
+    ///     /// Only the first paragraph is used.
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// assert_eq!(Jedi::variant_description(0), Some("The master of Anakin Skywalker."));
+    /// assert_eq!(Jedi::variant_description(1), None);
+    /// ```
+    #[inline]
+    fn variant_description(index: usize) -> Option<&'static str> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the shape of the fields of the variant at the given index.
+    ///
+    /// If you derive this trait, the shape is always known.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in
+    ///   [`Self::variant_names()`](EnumDebug::variant_names()).
+    ///
+    /// # Returns
+    /// The [`VariantShape`] of the variant, or [`None`] if it is unknown (or `index` is
+    /// out-of-bounds). By default, no shape is known.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::{EnumDebug, VariantShape};
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     Padawan(String),
+    ///     Master { name: String, padawans: usize },
+    /// }
+    ///
+    /// assert_eq!(Jedi::variant_shape(0), Some(VariantShape::Unit));
+    /// assert_eq!(Jedi::variant_shape(1), Some(VariantShape::Tuple(1)));
+    /// assert_eq!(Jedi::variant_shape(2), Some(VariantShape::Struct(&["name", "padawans"])));
+    /// assert_eq!(Jedi::variant_shape(3), None);
+    /// ```
+    #[inline]
+    fn variant_shape(index: usize) -> Option<VariantShape> {
+        let _ = index;
+        None
+    }
+
+    /// Returns whether the variant at the given index is hidden.
+    ///
+    /// Hidden variants can still be named, but integrations that list the variant names to users
+    /// (e.g., in help texts or shell completions) leave them out. If you derive this trait,
+    /// variants are hidden by marking them with `#[enum_debug(hidden)]`.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in
+    ///   [`Self::variant_names()`](EnumDebug::variant_names()).
+    ///
+    /// # Returns
+    /// True if the variant is hidden, or false otherwise (or if `index` is out-of-bounds). By
+    /// default, no variant is hidden.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     #[enum_debug(hidden)]
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// assert!(!Jedi::variant_hidden(0));
+    /// assert!(Jedi::variant_hidden(1));
+    /// ```
+    #[inline]
+    fn variant_hidden(index: usize) -> bool {
+        let _ = index;
+        false
+    }
+
 
 
     /// Returns a formatter for this enum that writes its variant name.
@@ -428,7 +951,7 @@ pub trait EnumDebug {
     /// assert_eq!(format!("{:?}", Jedi::AnakinSkywalker.variant()), "Jedi::AnakinSkywalker");
     /// ```
     #[inline]
-    fn variant(&self) -> EnumDebugFormatter<'_, Self> { EnumDebugFormatter { e: self } }
+    fn variant(&self) -> EnumDebugFormatter<'_, Self> { EnumDebugFormatter { e: self, qualified: false } }
 
     /// Returns an iterator over all variants in this enum.
     ///
@@ -478,5 +1001,82 @@ pub trait EnumDebug {
     /// ]);
     /// ```
     #[inline]
-    fn variants() -> Copied<std::slice::Iter<'static, &'static str>> { Self::variant_names().into_iter().copied() }
+    fn variants() -> Copied<std::slice::Iter<'static, &'static str>> { Self::variant_names().iter().copied() }
+}
+
+
+/// Constructs values of an [`EnumDebug`] type from the names of their variants.
+///
+/// This is only possible for enums whose variants don't carry any values (i.e., fieldless enums).
+/// If you use the `derive`-feature, the [`EnumDebug`](derive@EnumDebug)-derive macro
+/// automatically implements this trait for such enums.
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::{EnumDebug, FromVariant};
+///
+/// #[derive(Debug, EnumDebug, PartialEq)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// assert_eq!(Jedi::from_variant_index(1), Some(Jedi::AnakinSkywalker));
+/// assert_eq!(Jedi::from_variant_name("ObiWanKenobi"), Ok(Jedi::ObiWanKenobi));
+/// assert!(Jedi::from_variant_name("DarthVader").is_err());
+/// ```
+pub trait FromVariant: EnumDebug + Sized {
+    /// Constructs the variant with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// The variant with index `index`, or [`None`] if `index` is out-of-bounds.
+    fn from_variant_index(index: usize) -> Option<Self>;
+
+    /// Constructs the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant.
+    ///
+    /// # Returns
+    /// The variant called `name`.
+    ///
+    /// # Errors
+    /// This function errors if there is no variant called `name`.
+    #[inline]
+    fn from_variant_name(name: &str) -> Result<Self, UnknownVariantError> {
+        match Self::variant_index_of(name).and_then(Self::from_variant_index) {
+            Some(value) => Ok(value),
+            None => Err(UnknownVariantError::new::<Self>(name)),
+        }
+    }
+}
+
+// Standard impls
+impl<T: ?Sized + EnumDebug> EnumDebug for &T {
+    #[inline]
+    fn type_name() -> &'static str { T::type_name() }
+
+    #[inline]
+    fn variant_names() -> &'static [&'static str] { T::variant_names() }
+
+    #[inline]
+    fn variant_name(&self) -> &'static str { (**self).variant_name() }
+
+    #[inline]
+    fn variant_index(&self) -> usize { (**self).variant_index() }
+
+    #[inline]
+    fn variant_index_of(name: &str) -> Option<usize> { T::variant_index_of(name) }
+
+    #[inline]
+    fn variant_description(index: usize) -> Option<&'static str> { T::variant_description(index) }
+
+    #[inline]
+    fn variant_shape(index: usize) -> Option<VariantShape> { T::variant_shape(index) }
+
+    #[inline]
+    fn variant_hidden(index: usize) -> bool { T::variant_hidden(index) }
 }
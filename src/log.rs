@@ -0,0 +1,112 @@
+//  LOG.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:29:21
+//  Last edited:
+//    19 Oct 2026, 01:25:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `log`'s structured key-values for
+//!   [`EnumDebug`] types.
+//!
+//!   The [`EnumDebugFormatter`] returned by [`EnumDebug::variant()`]
+//!   implements [`ToValue`], so it can be passed as a key-value without
+//!   allocating. By default, it is recorded as the variant name; a
+//!   [qualified](EnumDebugFormatter::qualified()) formatter is recorded as
+//!   `Type::Variant` instead:
+//!   ```rust
+//!   use enum_debug::EnumDebug;
+//!   # use std::sync::Mutex;
+//!   # use log::kv::{Key, Value, VisitSource};
+//!   # use log::{Log, Metadata, Record};
+//!   #
+//!   # // A logger that remembers the key-values of all records
+//!   # static KVS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+//!   # struct Visitor;
+//!   # impl<'kvs> VisitSource<'kvs> for Visitor {
+//!   #     fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), log::kv::Error> {
+//!   #         KVS.lock().unwrap().push(format!("{key}={value}"));
+//!   #         Ok(())
+//!   #     }
+//!   # }
+//!   # struct Collector;
+//!   # impl Log for Collector {
+//!   #     fn enabled(&self, _: &Metadata) -> bool { true }
+//!   #     fn log(&self, record: &Record) { record.key_values().visit(&mut Visitor).unwrap(); }
+//!   #     fn flush(&self) {}
+//!   # }
+//!   # log::set_logger(&Collector).unwrap();
+//!   # log::set_max_level(log::LevelFilter::Info);
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Message {
+//!       Ping,
+//!       Data(Vec<u8>),
+//!   }
+//!
+//!   let msg = Message::Data(vec![42]);
+//!   log::info!(kind = msg.variant(), qualified_kind = msg.variant().qualified(); "Received message");
+//!   assert_eq!(*KVS.lock().unwrap(), vec!["kind=Data", "qualified_kind=Message::Data"]);
+//!   ```
+//!
+//!   Enums can also be passed as key-values themselves by adding
+//!   `#[enum_debug(log)]` to the [`EnumDebug`](derive@crate::EnumDebug)-derive
+//!   macro, which implements [`ToValue`] to record the variant name:
+//!   ```rust
+//!   use enum_debug::EnumDebug;
+//!   use log::kv::ToValue as _;
+//!
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(log)]
+//!   enum Message {
+//!       Ping,
+//!       Data(Vec<u8>),
+//!   }
+//!
+//!   let msg = Message::Data(vec![42]);
+//!   log::info!(kind = msg; "Received message");
+//!   assert_eq!(msg.to_value().to_borrowed_str(), Some("Data"));
+//!   ```
+//!
+//!   This module is only available when the `log`-feature is used.
+//
+
+use ::log::kv::{ToValue, Value};
+
+use crate::{EnumDebug, EnumDebugFormatter};
+
+
+/***** LIBRARY *****/
+impl<'a, T: EnumDebug> ToValue for EnumDebugFormatter<'a, T> {
+    /// Records the formatter as the variant name.
+    ///
+    /// If the formatter is [qualified](EnumDebugFormatter::qualified()), it is recorded as
+    /// `Type::Variant` instead.
+    #[inline]
+    fn to_value(&self) -> Value<'_> { if self.is_qualified() { Value::from_display(self) } else { Value::from(self.e.variant_name()) } }
+}
+
+
+
+/// Returns the [variant name](EnumDebug::variant_name()) of a value as a `log` value.
+///
+/// This is what the [`ToValue`] derived with `#[enum_debug(log)]` records.
+///
+/// # Arguments
+/// - `value`: The value of which to record the variant name.
+///
+/// # Returns
+/// A [`Value`] that borrows the `&'static str` variant name.
+#[inline]
+pub fn to_value<T: ?Sized + EnumDebug>(value: &T) -> Value<'static> { Value::from(value.variant_name()) }
+
+
+
+/// Re-exports used by the implementation derived with `#[enum_debug(log)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::log;
+}
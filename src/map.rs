@@ -0,0 +1,499 @@
+//  MAP.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:18:38
+//  Last edited:
+//    18 Oct 2026, 23:20:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`VariantMap`], a dense map keyed by the variants of an
+//!   [`EnumDebug`] type.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use crate::set::QualifiedName;
+use crate::EnumDebug;
+
+
+/***** AUXILLARY *****/
+/// Represents a single slot in a [`VariantMap`], which may or may not have a value yet.
+///
+/// This entry is returned by [`VariantMap::entry()`].
+pub struct Entry<'m, T, V> {
+    /// The slot of the variant in the map.
+    slot:  &'m mut Option<V>,
+    /// The index of the variant this entry belongs to.
+    index: usize,
+    /// Remembers the type of which this entry is a variant.
+    _t:    PhantomData<fn() -> T>,
+}
+impl<'m, T: EnumDebug, V> Entry<'m, T, V> {
+    /// Returns the name of the variant this entry belongs to.
+    #[inline]
+    pub fn name(&self) -> &'static str { T::variant_names()[self.index] }
+
+    /// Returns the index of the variant this entry belongs to.
+    #[inline]
+    pub fn index(&self) -> usize { self.index }
+
+    /// Modifies the value of this entry if it has any.
+    ///
+    /// # Arguments
+    /// - `f`: Some closure that updates the existing value.
+    ///
+    /// # Returns
+    /// The same entry, for chaining.
+    #[inline]
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        if let Some(value) = self.slot.as_mut() {
+            f(value);
+        }
+        self
+    }
+
+    /// Ensures this entry has a value by inserting the given one if it hasn't.
+    ///
+    /// # Arguments
+    /// - `default`: The value to insert if there is none yet.
+    ///
+    /// # Returns
+    /// A mutable reference to the (new) value of this entry.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'m mut V { self.slot.get_or_insert(default) }
+
+    /// Ensures this entry has a value by inserting the result of the given closure if it hasn't.
+    ///
+    /// # Arguments
+    /// - `default`: A closure computing the value to insert if there is none yet.
+    ///
+    /// # Returns
+    /// A mutable reference to the (new) value of this entry.
+    #[inline]
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'m mut V { self.slot.get_or_insert_with(default) }
+}
+impl<'m, T: EnumDebug, V: Default> Entry<'m, T, V> {
+    /// Ensures this entry has a value by inserting [`V::default()`](Default::default()) if it
+    /// hasn't.
+    ///
+    /// # Returns
+    /// A mutable reference to the (new) value of this entry.
+    #[inline]
+    pub fn or_default(self) -> &'m mut V { self.slot.get_or_insert_with(V::default) }
+}
+
+
+
+/// Iterates over the variants and values in a [`VariantMap`] in declaration order.
+///
+/// This iterator is returned by [`VariantMap::iter()`].
+pub struct Iter<'m, T, V> {
+    /// The slots to iterate over, paired with their index.
+    slots: std::iter::Enumerate<std::slice::Iter<'m, Option<V>>>,
+    /// Remembers the type of which the variants are iterated.
+    _t:    PhantomData<fn() -> T>,
+}
+impl<'m, T: EnumDebug, V> Iterator for Iter<'m, T, V> {
+    type Item = (&'static str, &'m V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.by_ref().find_map(|(index, slot)| slot.as_ref().map(|value| (T::variant_names()[index], value)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.slots.size_hint().1) }
+}
+
+/// Mutably iterates over the variants and values in a [`VariantMap`] in declaration order.
+///
+/// This iterator is returned by [`VariantMap::iter_mut()`].
+pub struct IterMut<'m, T, V> {
+    /// The slots to iterate over, paired with their index.
+    slots: std::iter::Enumerate<std::slice::IterMut<'m, Option<V>>>,
+    /// Remembers the type of which the variants are iterated.
+    _t:    PhantomData<fn() -> T>,
+}
+impl<'m, T: EnumDebug, V> Iterator for IterMut<'m, T, V> {
+    type Item = (&'static str, &'m mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.by_ref().find_map(|(index, slot)| slot.as_mut().map(|value| (T::variant_names()[index], value)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.slots.size_hint().1) }
+}
+
+/// Iterates over the variants and values in a [`VariantMap`] in declaration order, by ownership.
+///
+/// This iterator is returned by [`VariantMap::into_iter()`].
+pub struct IntoIter<T, V> {
+    /// The slots to iterate over, paired with their index.
+    slots: std::iter::Enumerate<std::vec::IntoIter<Option<V>>>,
+    /// Remembers the type of which the variants are iterated.
+    _t:    PhantomData<fn() -> T>,
+}
+impl<T: EnumDebug, V> Iterator for IntoIter<T, V> {
+    type Item = (&'static str, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.by_ref().find_map(|(index, slot)| slot.map(|value| (T::variant_names()[index], value)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.slots.size_hint().1) }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A map from the variants of an [`EnumDebug`] type to values.
+///
+/// Internally, the map is an array with one slot per entry in [`EnumDebug::variant_names()`].
+/// Lookups are therefore a simple index operation instead of a hash, and iteration always follows
+/// the order in which the variants are declared.
+///
+/// The [`Debug`]-formatter writes the map as `{Type::Variant: value, ...}`.
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::{EnumDebug, VariantMap};
+///
+/// #[derive(EnumDebug)]
+/// enum Event {
+///     Connect { id: u32 },
+///     Message(String),
+///     Disconnect,
+/// }
+///
+/// let mut stats: VariantMap<Event, u64> = VariantMap::new();
+/// for event in [Event::Message("Hello".into()), Event::Connect { id: 42 }, Event::Message("there".into())] {
+///     *stats.entry(&event).or_default() += 1;
+/// }
+///
+/// assert_eq!(stats[&Event::Message(String::new())], 2);
+/// assert_eq!(stats.get(&Event::Disconnect), None);
+/// assert_eq!(stats.iter().collect::<Vec<_>>(), vec![("Connect", &1), ("Message", &2)]);
+/// assert_eq!(format!("{stats:?}"), "{Event::Connect: 1, Event::Message: 2}");
+/// ```
+pub struct VariantMap<T, V> {
+    /// One slot per variant, where the `i`th slot belongs to the `i`th variant.
+    slots: Box<[Option<V>]>,
+    /// Remembers the type of which the variants are the keys.
+    _t:    PhantomData<fn() -> T>,
+}
+
+impl<T: EnumDebug, V> Default for VariantMap<T, V> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<T: EnumDebug, V> VariantMap<T, V> {
+    /// Constructor for the VariantMap that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new VariantMap without a value for any variant.
+    #[inline]
+    pub fn new() -> Self { Self { slots: T::variant_names().iter().map(|_| None).collect(), _t: PhantomData } }
+
+    /// Constructor for the VariantMap that initializes it with a value for every variant.
+    ///
+    /// # Arguments
+    /// - `f`: A closure that produces the value for the variant with the given name.
+    ///
+    /// # Returns
+    /// A new VariantMap with a value for every variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::{EnumDebug, VariantMap};
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// let lengths: VariantMap<Jedi, usize> = VariantMap::from_fn(str::len);
+    /// assert_eq!(lengths[&Jedi::AnakinSkywalker], 15);
+    /// ```
+    #[inline]
+    pub fn from_fn(f: impl FnMut(&'static str) -> V) -> Self {
+        Self { slots: T::variant_names().iter().copied().map(f).map(Some).collect(), _t: PhantomData }
+    }
+
+
+
+    /// Sets the value for the variant of the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    /// - `value`: The value to set.
+    ///
+    /// # Returns
+    /// The old value of the variant, if any.
+    #[inline]
+    pub fn insert(&mut self, key: &T, value: V) -> Option<V> { self.insert_index(key.variant_index(), value) }
+
+    /// Sets the value for the variant with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    /// - `value`: The value to set.
+    ///
+    /// # Returns
+    /// The old value of the variant, if any.
+    ///
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the variants of `T`.
+    #[inline]
+    pub fn insert_index(&mut self, index: usize, value: V) -> Option<V> { self.slot_mut(index).replace(value) }
+
+    /// Removes the value for the variant of the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    ///
+    /// # Returns
+    /// The removed value, if any.
+    #[inline]
+    pub fn remove(&mut self, key: &T) -> Option<V> { self.slots.get_mut(key.variant_index()).and_then(Option::take) }
+
+    /// Removes all values from the map.
+    #[inline]
+    pub fn clear(&mut self) { self.slots.iter_mut().for_each(|slot| *slot = None); }
+
+    /// Returns the entry of the variant of the given key for in-place manipulation.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    ///
+    /// # Returns
+    /// An [`Entry`] that refers to the variant's slot in this map.
+    #[inline]
+    pub fn entry(&mut self, key: &T) -> Entry<'_, T, V> { self.entry_index(key.variant_index()) }
+
+    /// Returns the entry of the variant with the given index for in-place manipulation.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// An [`Entry`] that refers to the variant's slot in this map.
+    ///
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the variants of `T`.
+    #[inline]
+    pub fn entry_index(&mut self, index: usize) -> Entry<'_, T, V> { Entry { slot: self.slot_mut(index), index, _t: PhantomData } }
+
+    /// Returns the slot of the variant with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// A mutable reference to the slot.
+    ///
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the variants of `T`.
+    #[inline]
+    fn slot_mut(&mut self, index: usize) -> &mut Option<V> {
+        let n_variants: usize = self.slots.len();
+        match self.slots.get_mut(index) {
+            Some(slot) => slot,
+            None => panic!("Variant index {} is out-of-bounds for type {} with {} variants", index, T::type_name(), n_variants),
+        }
+    }
+
+
+
+    /// Returns the value for the variant of the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    ///
+    /// # Returns
+    /// A reference to the value, or [`None`] if the variant has none.
+    #[inline]
+    pub fn get(&self, key: &T) -> Option<&V> { self.get_index(key.variant_index()) }
+
+    /// Returns the value for the variant with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// A reference to the value, or [`None`] if the variant has none (or `index` is
+    /// out-of-bounds).
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<&V> { self.slots.get(index).and_then(Option::as_ref) }
+
+    /// Returns the value for the variant with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant.
+    ///
+    /// # Returns
+    /// A reference to the value, or [`None`] if the variant has none (or `name` is not a variant
+    /// of `T`).
+    #[inline]
+    pub fn get_name(&self, name: &str) -> Option<&V> { T::variant_index_of(name).and_then(|index| self.get_index(index)) }
+
+    /// Returns the value for the variant of the given key mutably.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    ///
+    /// # Returns
+    /// A mutable reference to the value, or [`None`] if the variant has none.
+    #[inline]
+    pub fn get_mut(&mut self, key: &T) -> Option<&mut V> { self.get_index_mut(key.variant_index()) }
+
+    /// Returns the value for the variant with the given index mutably.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// A mutable reference to the value, or [`None`] if the variant has none (or `index` is
+    /// out-of-bounds).
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<&mut V> { self.slots.get_mut(index).and_then(Option::as_mut) }
+
+    /// Checks whether the variant of the given key has a value.
+    ///
+    /// # Arguments
+    /// - `key`: The value of which the variant is used as key.
+    ///
+    /// # Returns
+    /// True if there is a value for the variant of `key`, or false otherwise.
+    #[inline]
+    pub fn contains_key(&self, key: &T) -> bool { self.get(key).is_some() }
+
+    /// Returns the number of variants with a value.
+    #[inline]
+    pub fn len(&self) -> usize { self.slots.iter().filter(|slot| slot.is_some()).count() }
+
+    /// Returns whether no variant has a value.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.slots.iter().all(Option::is_none) }
+
+
+
+    /// Returns an iterator over the variants with a value and their values.
+    ///
+    /// # Returns
+    /// An [`Iter`] that yields pairs of variant names and values in the order they appear in
+    /// [`EnumDebug::variant_names()`].
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, V> { Iter { slots: self.slots.iter().enumerate(), _t: PhantomData } }
+
+    /// Returns an iterator over the variants with a value and their values, which can be
+    /// modified.
+    ///
+    /// # Returns
+    /// An [`IterMut`] that yields pairs of variant names and mutable values in the order they
+    /// appear in [`EnumDebug::variant_names()`].
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, V> { IterMut { slots: self.slots.iter_mut().enumerate(), _t: PhantomData } }
+
+    /// Returns an iterator over the values in this map.
+    ///
+    /// # Returns
+    /// An iterator that yields the values in the order of their variants in
+    /// [`EnumDebug::variant_names()`].
+    #[inline]
+    pub fn values(&self) -> impl '_ + Iterator<Item = &V> { self.slots.iter().filter_map(Option::as_ref) }
+}
+
+impl<T, V: Clone> Clone for VariantMap<T, V> {
+    #[inline]
+    fn clone(&self) -> Self { Self { slots: self.slots.clone(), _t: PhantomData } }
+}
+impl<T, V: Eq> Eq for VariantMap<T, V> {}
+impl<T, V: PartialEq> PartialEq for VariantMap<T, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.slots == other.slots }
+}
+impl<T, V: Hash> Hash for VariantMap<T, V> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.slots.hash(state) }
+}
+
+impl<T: EnumDebug, V: Debug> Debug for VariantMap<T, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_map().entries(self.iter().map(|(name, value)| (QualifiedName::<T>::new(name), value))).finish()
+    }
+}
+
+impl<T: EnumDebug, V> Index<&T> for VariantMap<T, V> {
+    type Output = V;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: &T) -> &Self::Output {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("No value for variant {:?} in VariantMap", key.variant()),
+        }
+    }
+}
+impl<T: EnumDebug, V> IndexMut<&T> for VariantMap<T, V> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, key: &T) -> &mut Self::Output {
+        match self.get_mut(key) {
+            Some(value) => value,
+            None => panic!("No value for variant {:?} in VariantMap", key.variant()),
+        }
+    }
+}
+
+impl<'a, T: EnumDebug, V> Extend<(&'a T, V)> for VariantMap<T, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a T, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+impl<'a, T: EnumDebug, V> FromIterator<(&'a T, V)> for VariantMap<T, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (&'a T, V)>>(iter: I) -> Self {
+        let mut map: Self = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+impl<T: EnumDebug, V> IntoIterator for VariantMap<T, V> {
+    type IntoIter = IntoIter<T, V>;
+    type Item = (&'static str, V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter { slots: self.slots.into_vec().into_iter().enumerate(), _t: PhantomData } }
+}
+impl<'m, T: EnumDebug, V> IntoIterator for &'m VariantMap<T, V> {
+    type IntoIter = Iter<'m, T, V>;
+    type Item = (&'static str, &'m V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl<'m, T: EnumDebug, V> IntoIterator for &'m mut VariantMap<T, V> {
+    type IntoIter = IterMut<'m, T, V>;
+    type Item = (&'static str, &'m mut V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
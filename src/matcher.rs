@@ -0,0 +1,282 @@
+//  MATCHER.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:22:10
+//  Last edited:
+//    18 Oct 2026, 23:22:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`VariantMatcher`], which matches variants by glob (or
+//!   regex) patterns over their names.
+//
+
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+
+use crate::set::VariantSet;
+use crate::EnumDebug;
+
+
+/***** ERRORS *****/
+/// Describes why a pattern could not be compiled into a [`VariantMatcher`].
+#[derive(Debug)]
+pub enum PatternError {
+    /// The pattern was valid, but it did not match any variant.
+    NoMatch { pattern: String, type_name: &'static str, variants: &'static [&'static str] },
+    /// The pattern was not a valid regular expression.
+    #[cfg(feature = "regex")]
+    IllegalRegex { pattern: String, err: regex::Error },
+}
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use PatternError::*;
+        match self {
+            NoMatch { pattern, type_name, variants } => {
+                write!(f, "Pattern '{pattern}' does not match any variant of type {type_name} (variants are: ")?;
+                for (i, variant) in variants.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{variant}'")?;
+                }
+                write!(f, ")")
+            },
+            #[cfg(feature = "regex")]
+            IllegalRegex { pattern, .. } => write!(f, "Pattern '{pattern}' is not a valid regular expression"),
+        }
+    }
+}
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn 'static + Error)> {
+        use PatternError::*;
+        match self {
+            NoMatch { .. } => None,
+            #[cfg(feature = "regex")]
+            IllegalRegex { err, .. } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Matches a name against a glob pattern.
+///
+/// # Arguments
+/// - `pattern`: The glob pattern, where `*` matches any (possibly empty) sequence of characters and
+///   `?` matches exactly one character. All other characters match themselves.
+/// - `name`: The name to match.
+///
+/// # Returns
+/// True if `pattern` matches the whole of `name`, or false otherwise.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Greedy matching with backtracking to the last star
+    let (mut p, mut n): (usize, usize) = (0, 0);
+    let mut last_star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                last_star = Some((p, n));
+                p += 1;
+            },
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match last_star {
+                // Let the star eat one more character
+                Some((star_p, star_n)) => {
+                    last_star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                },
+                None => return false,
+            },
+        }
+    }
+    // Only stars may remain
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Matches the variants of an [`EnumDebug`] type by patterns over their names.
+///
+/// Patterns are matched against [`EnumDebug::variant_names()`] once, when the matcher is built.
+/// Afterwards, checking a value is just a lookup of its [variant index](EnumDebug::variant_index())
+/// in a [`VariantSet`].
+///
+/// Patterns that do not match any variant are rejected with a [`PatternError::NoMatch`], as these
+/// are most likely typos in configuration.
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::matcher::VariantMatcher;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Error {
+///     IoRead(std::io::Error),
+///     IoWrite(std::io::Error),
+///     ReadTimeout,
+///     InvalidInput(String),
+/// }
+///
+/// let matcher: VariantMatcher<Error> = VariantMatcher::globs(["Io*", "*Timeout"]).unwrap();
+/// assert!(matcher.matches(&Error::ReadTimeout));
+/// assert!(matcher.matches(&Error::IoWrite(std::io::ErrorKind::Other.into())));
+/// assert!(!matcher.matches(&Error::InvalidInput("foo".into())));
+///
+/// // Typos are reported
+/// let err = VariantMatcher::<Error>::glob("*Timout").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Pattern '*Timout' does not match any variant of type Error (variants are: 'IoRead', 'IoWrite', 'ReadTimeout', \
+///      'InvalidInput')"
+/// );
+/// ```
+pub struct VariantMatcher<T> {
+    /// The variants that are matched.
+    set: VariantSet<T>,
+}
+impl<T: EnumDebug> VariantMatcher<T> {
+    /// Constructor for the VariantMatcher that matches variants by a glob pattern.
+    ///
+    /// In the pattern, `*` matches any (possibly empty) sequence of characters and `?` matches
+    /// exactly one character. The pattern must match the whole variant name.
+    ///
+    /// # Arguments
+    /// - `pattern`: The glob pattern to match the (unqualified) variant names against.
+    ///
+    /// # Returns
+    /// A new VariantMatcher that matches all variants matched by `pattern`.
+    ///
+    /// # Errors
+    /// This function errors if `pattern` does not match any variant.
+    #[inline]
+    pub fn glob(pattern: &str) -> Result<Self, PatternError> { Self::globs([pattern]) }
+
+    /// Constructor for the VariantMatcher that matches variants by any of multiple glob patterns.
+    ///
+    /// See [`VariantMatcher::glob()`] for the syntax of the patterns.
+    ///
+    /// # Arguments
+    /// - `patterns`: The glob patterns to match the (unqualified) variant names against.
+    ///
+    /// # Returns
+    /// A new VariantMatcher that matches all variants matched by at least one of `patterns`.
+    ///
+    /// # Errors
+    /// This function errors if any of the `patterns` does not match any variant.
+    pub fn globs<'p>(patterns: impl IntoIterator<Item = &'p str>) -> Result<Self, PatternError> {
+        let mut set: VariantSet<T> = VariantSet::new();
+        for pattern in patterns {
+            let mut matched: bool = false;
+            for (index, name) in T::variant_names().iter().enumerate() {
+                if glob_matches(pattern, name) {
+                    set.insert_index(index);
+                    matched = true;
+                }
+            }
+            if !matched {
+                return Err(PatternError::NoMatch { pattern: pattern.into(), type_name: T::type_name(), variants: T::variant_names() });
+            }
+        }
+        Ok(Self { set })
+    }
+
+    /// Constructor for the VariantMatcher that matches variants by a regular expression.
+    ///
+    /// The expression must match the whole variant name, i.e., it is implicitly anchored at both
+    /// ends.
+    ///
+    /// # Arguments
+    /// - `pattern`: The regular expression to match the (unqualified) variant names against.
+    ///
+    /// # Returns
+    /// A new VariantMatcher that matches all variants matched by `pattern`.
+    ///
+    /// # Errors
+    /// This function errors if `pattern` is not a valid regular expression, or if it does not
+    /// match any variant.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::matcher::VariantMatcher;
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Error {
+    ///     IoRead,
+    ///     IoWrite,
+    ///     ReadTimeout,
+    /// }
+    ///
+    /// let matcher: VariantMatcher<Error> = VariantMatcher::regex("Io(Read|Write)").unwrap();
+    /// assert!(matcher.matches(&Error::IoRead));
+    /// assert!(!matcher.matches(&Error::ReadTimeout));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, PatternError> {
+        let re: regex::Regex = match regex::Regex::new(&format!("^(?:{pattern})$")) {
+            Ok(re) => re,
+            Err(err) => return Err(PatternError::IllegalRegex { pattern: pattern.into(), err }),
+        };
+
+        // Collect the matches
+        let mut set: VariantSet<T> = VariantSet::new();
+        for (index, name) in T::variant_names().iter().enumerate() {
+            if re.is_match(name) {
+                set.insert_index(index);
+            }
+        }
+        if set.is_empty() {
+            return Err(PatternError::NoMatch { pattern: pattern.into(), type_name: T::type_name(), variants: T::variant_names() });
+        }
+        Ok(Self { set })
+    }
+
+
+
+    /// Checks whether the variant of the given value is matched.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to check the variant.
+    ///
+    /// # Returns
+    /// True if the variant of `value` matched the pattern(s) of this matcher, or false otherwise.
+    #[inline]
+    pub fn matches(&self, value: &T) -> bool { self.set.contains(value) }
+
+    /// Returns the set of variants that are matched.
+    #[inline]
+    pub fn set(&self) -> &VariantSet<T> { &self.set }
+
+    /// Returns the set of variants that are matched by ownership.
+    #[inline]
+    pub fn into_set(self) -> VariantSet<T> { self.set }
+}
+
+impl<T> Clone for VariantMatcher<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self { set: self.set.clone() } }
+}
+impl<T: EnumDebug> Debug for VariantMatcher<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_struct("VariantMatcher").field("set", &self.set).finish() }
+}
+impl<T> From<VariantMatcher<T>> for VariantSet<T> {
+    #[inline]
+    fn from(value: VariantMatcher<T>) -> Self { value.set }
+}
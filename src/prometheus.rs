@@ -0,0 +1,301 @@
+//  PROMETHEUS.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:30:04
+//  Last edited:
+//    18 Oct 2026, 23:34:17
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`VariantCounterVec`], a Prometheus counter with one series
+//!   per variant of an [`EnumDebug`] type.
+//!
+//!   This module is only available when the `prometheus`-feature is used.
+//
+
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+
+use crate::counter::VariantCounter;
+use crate::EnumDebug;
+
+
+/***** ERRORS *****/
+/// Describes why a [`VariantCounterVec`] could not be created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MetricError {
+    /// The name of the metric is not a valid Prometheus metric name.
+    IllegalMetricName { name: String },
+    /// The name of a label is not a valid Prometheus label name.
+    IllegalLabelName { name: String },
+    /// A constant label has the same name as the variant label (or another constant label).
+    DuplicateLabel { name: String },
+}
+impl Display for MetricError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use MetricError::*;
+        match self {
+            IllegalMetricName { name } => write!(f, "'{name}' is not a valid Prometheus metric name"),
+            IllegalLabelName { name } => write!(f, "'{name}' is not a valid Prometheus label name"),
+            DuplicateLabel { name } => write!(f, "Label '{name}' is given more than once"),
+        }
+    }
+}
+impl Error for MetricError {}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether the given string is a valid Prometheus metric name.
+///
+/// # Arguments
+/// - `name`: The name to check.
+///
+/// # Returns
+/// True if `name` matches `[a-zA-Z_:][a-zA-Z0-9_:]*`, or false otherwise.
+fn is_metric_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map(|c| c.is_ascii_alphabetic() || c == '_' || c == ':').unwrap_or(false) && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+/// Checks whether the given string is a valid Prometheus label name.
+///
+/// # Arguments
+/// - `name`: The name to check.
+///
+/// # Returns
+/// True if `name` matches `[a-zA-Z_][a-zA-Z0-9_]*` and does not start with `__` (which is
+/// reserved), or false otherwise.
+fn is_label_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    !name.starts_with("__")
+        && chars.next().map(|c| c.is_ascii_alphabetic() || c == '_').unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Writes a string with the given characters escaped as in the Prometheus text format.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `value`: The string to write.
+/// - `quotes`: Whether to escape double quotes too (as in label values) or not (as in help texts).
+///
+/// # Errors
+/// This function errors if we failed to write to `f`.
+fn write_escaped(f: &mut Formatter<'_>, value: &str, quotes: bool) -> FResult {
+    for c in value.chars() {
+        match c {
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '"' if quotes => write!(f, "\\\"")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    Ok(())
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A Prometheus counter with one series per variant of an [`EnumDebug`] type.
+///
+/// Variant names make good metric labels, as [`EnumDebug::variant_names()`] is a closed set. This
+/// counter labels its series with exactly those names. All series are created up front, so they
+/// are reported (as zero) even before the first occurrence of a variant. And as values can only
+/// be counted by variant, the number of series never exceeds the number of variants (see
+/// [`VariantCounterVec::cardinality()`]).
+///
+/// The [`Display`]-formatter writes the counter in the
+/// [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/).
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::prometheus::VariantCounterVec;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Request {
+///     Get { path: String },
+///     Post { path: String, body: Vec<u8> },
+/// }
+///
+/// let requests: VariantCounterVec<Request> = VariantCounterVec::new("http_requests_total", "Number of handled requests.")
+///     .unwrap()
+///     .with_label_name("method")
+///     .unwrap()
+///     .with_const_label("service", "api")
+///     .unwrap();
+/// requests.inc(&Request::Get { path: "/".into() });
+/// requests.inc(&Request::Get { path: "/index.html".into() });
+///
+/// assert_eq!(requests.cardinality(), 2);
+/// assert_eq!(
+///     requests.to_string(),
+///     r#"# HELP http_requests_total Number of handled requests.
+/// ## TYPE http_requests_total counter
+/// http_requests_total{service="api",method="Get"} 2
+/// http_requests_total{service="api",method="Post"} 0
+/// "#
+/// );
+/// ```
+pub struct VariantCounterVec<T> {
+    /// The name of the metric.
+    name:         String,
+    /// The help text of the metric.
+    help:         String,
+    /// The name of the label that carries the variant name.
+    label_name:   String,
+    /// Any labels with the same value for every series.
+    const_labels: Vec<(String, String)>,
+    /// The counts per variant.
+    counter:      VariantCounter<T>,
+}
+impl<T: EnumDebug> VariantCounterVec<T> {
+    /// Constructor for the VariantCounterVec.
+    ///
+    /// The series are labelled with a `variant`-label by default. Use
+    /// [`VariantCounterVec::with_label_name()`] to change this.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the metric. By convention, counters end in `_total`.
+    /// - `help`: A description of the metric.
+    ///
+    /// # Returns
+    /// A new VariantCounterVec with all series at zero.
+    ///
+    /// # Errors
+    /// This function errors if `name` is not a valid Prometheus metric name.
+    pub fn new(name: impl Into<String>, help: impl Into<String>) -> Result<Self, MetricError> {
+        let name: String = name.into();
+        if !is_metric_name(&name) {
+            return Err(MetricError::IllegalMetricName { name });
+        }
+        Ok(Self { name, help: help.into(), label_name: "variant".into(), const_labels: Vec::new(), counter: VariantCounter::new() })
+    }
+
+    /// Changes the name of the label that carries the variant name.
+    ///
+    /// # Arguments
+    /// - `name`: The new name of the label.
+    ///
+    /// # Returns
+    /// The same VariantCounterVec, for chaining.
+    ///
+    /// # Errors
+    /// This function errors if `name` is not a valid Prometheus label name, or if it is already
+    /// used by a constant label.
+    pub fn with_label_name(mut self, name: impl Into<String>) -> Result<Self, MetricError> {
+        let name: String = name.into();
+        if !is_label_name(&name) {
+            return Err(MetricError::IllegalLabelName { name });
+        }
+        if self.const_labels.iter().any(|(label, _)| *label == name) {
+            return Err(MetricError::DuplicateLabel { name });
+        }
+        self.label_name = name;
+        Ok(self)
+    }
+
+    /// Adds a label that has the same value for every series.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the label.
+    /// - `value`: The value of the label.
+    ///
+    /// # Returns
+    /// The same VariantCounterVec, for chaining.
+    ///
+    /// # Errors
+    /// This function errors if `name` is not a valid Prometheus label name, or if it is already
+    /// used by another label.
+    pub fn with_const_label(mut self, name: impl Into<String>, value: impl Into<String>) -> Result<Self, MetricError> {
+        let name: String = name.into();
+        if !is_label_name(&name) {
+            return Err(MetricError::IllegalLabelName { name });
+        }
+        if name == self.label_name || self.const_labels.iter().any(|(label, _)| *label == name) {
+            return Err(MetricError::DuplicateLabel { name });
+        }
+        self.const_labels.push((name, value.into()));
+        Ok(self)
+    }
+
+
+
+    /// Increments the series of the variant of the given value by one.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to count the variant.
+    #[inline]
+    pub fn inc(&self, value: &T) { self.counter.increment(value) }
+
+    /// Increments the series of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to count the variant.
+    /// - `n`: The amount to increment with.
+    #[inline]
+    pub fn inc_by(&self, value: &T, n: u64) { self.counter.add(value, n) }
+
+    /// Returns the current value of the series of the variant of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to get the variant's series.
+    ///
+    /// # Returns
+    /// The current count of the variant.
+    #[inline]
+    pub fn get(&self, value: &T) -> u64 { self.counter.get(value) }
+
+    /// Returns the number of series of this counter.
+    ///
+    /// This always equals the number of variants of `T`.
+    #[inline]
+    pub fn cardinality(&self) -> usize { T::variant_names().len() }
+
+    /// Returns the name of this metric.
+    #[inline]
+    pub fn name(&self) -> &str { &self.name }
+
+    /// Returns the underlying [`VariantCounter`].
+    #[inline]
+    pub fn counter(&self) -> &VariantCounter<T> { &self.counter }
+}
+impl<T: EnumDebug> Debug for VariantCounterVec<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_struct("VariantCounterVec")
+            .field("name", &self.name)
+            .field("help", &self.help)
+            .field("label_name", &self.label_name)
+            .field("const_labels", &self.const_labels)
+            .field("counter", &self.counter)
+            .finish()
+    }
+}
+impl<T: EnumDebug> Display for VariantCounterVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "# HELP {} ", self.name)?;
+        write_escaped(f, &self.help, false)?;
+        writeln!(f)?;
+        writeln!(f, "# TYPE {} counter", self.name)?;
+        for (variant, count) in self.counter.histogram().iter() {
+            write!(f, "{}{{", self.name)?;
+            for (name, value) in &self.const_labels {
+                write!(f, "{name}=\"")?;
+                write_escaped(f, value, true)?;
+                write!(f, "\",")?;
+            }
+            write!(f, "{}=\"", self.label_name)?;
+            write_escaped(f, variant, true)?;
+            writeln!(f, "\"}} {count}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,712 @@
+//  PROTO.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:44:37
+//  Last edited:
+//    19 Oct 2026, 01:41:30
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a generator for Protocol Buffers (`.proto`) enums that
+//!   mirror the variants of [`EnumDebug`] types.
+//!
+//!   The generated enums follow the proto3 style guide: values are
+//!   written in SCREAMING_SNAKE_CASE and prefixed with the name of the enum,
+//!   and the first value is an `_UNSPECIFIED` value with number zero.
+//!   ```rust
+//!   use enum_debug::proto::ProtoGenerator;
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!       MaceWindu,
+//!   }
+//!
+//!   let generator = ProtoGenerator::new().package("jedi.v1").add::<Jedi>();
+//!   let proto: String = generator.generate(None).unwrap();
+//!   assert_eq!(
+//!       proto,
+//!       r#"// Generated by enum-debug. Do not edit this file manually.
+//!   syntax = "proto3";
+//!
+//!   package jedi.v1;
+//!
+//!   enum Jedi {
+//!     JEDI_UNSPECIFIED = 0;
+//!     JEDI_OBI_WAN_KENOBI = 1;
+//!     JEDI_ANAKIN_SKYWALKER = 2;
+//!     JEDI_MACE_WINDU = 3;
+//!   }
+//!   "#
+//!   );
+//!   ```
+//!
+//!   Numbers are kept stable by passing the previous version of the file. Values
+//!   that existed before keep their number, new values are numbered after
+//!   any number used before, and (if asked) the numbers and names of removed
+//!   values are reserved:
+//!   ```rust
+//!   use enum_debug::proto::ProtoGenerator;
+//!   use enum_debug::EnumDebug;
+//!
+//!   // The new version of the `Jedi` enum above
+//!   #[derive(EnumDebug)]
+//!   enum Jedi {
+//!       Ahsoka,
+//!       ObiWanKenobi,
+//!       MaceWindu,
+//!   }
+//!
+//!   let previous = r#"
+//!   enum Jedi {
+//!     JEDI_UNSPECIFIED = 0;
+//!     JEDI_OBI_WAN_KENOBI = 1;
+//!     JEDI_ANAKIN_SKYWALKER = 2; // Fell to the dark side
+//!     JEDI_MACE_WINDU = 3;
+//!   }
+//!   "#;
+//!
+//!   let generator = ProtoGenerator::new().reserve_removed().add::<Jedi>();
+//!   assert_eq!(
+//!       generator.generate(Some(previous)).unwrap(),
+//!       r#"// Generated by enum-debug. Do not edit this file manually.
+//!   syntax = "proto3";
+//!
+//!   enum Jedi {
+//!     JEDI_UNSPECIFIED = 0;
+//!     JEDI_AHSOKA = 4;
+//!     JEDI_OBI_WAN_KENOBI = 1;
+//!     JEDI_MACE_WINDU = 3;
+//!     reserved 2;
+//!     reserved "JEDI_ANAKIN_SKYWALKER";
+//!   }
+//!   "#
+//!   );
+//!   ```
+//!
+//!   [`ProtoGenerator::write()`] and [`ProtoGenerator::check()`] do this
+//!   automatically with the file they write or check. Because of that,
+//!   [`ProtoGenerator::check()`] cannot detect numbers that were changed by
+//!   hand; use [`ProtoGenerator::check_against()`] with a trusted previous
+//!   version of the file for that.
+//
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::path::Path;
+
+use crate::generated::{check_generated, is_identifier, read_generated, write_generated, GeneratedFileError, HEADER};
+use crate::{screaming_snake_case, EnumDebug};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when generating `.proto` enums.
+#[derive(Debug)]
+pub enum ProtoError {
+    /// The package is not a dot-separated list of valid Protocol Buffers identifiers.
+    IllegalPackage { package: String },
+    /// The type name of an [`EnumDebug`] type is not a valid Protocol Buffers identifier.
+    IllegalTypeName { name: &'static str },
+    /// Two types have the same type name.
+    DuplicateTypeName { name: &'static str },
+    /// The value name of a variant is not a valid Protocol Buffers identifier.
+    IllegalValueName { type_name: &'static str, name: String },
+    /// Two variants (or a variant and an `_UNSPECIFIED` value) map to the same value name.
+    DuplicateValueName { type_name: &'static str, name: String },
+    /// A new variant maps to a value name that is reserved in the previous file.
+    ReservedValueName { type_name: &'static str, name: String },
+    /// There is no number left for a new variant that is neither used nor reserved.
+    NoFreeNumber { type_name: &'static str, name: String },
+    /// Failed to parse the previous file.
+    IllegalPrevious { reason: String },
+    /// Failed to write the generated file, or to check an existing one.
+    File { err: GeneratedFileError },
+}
+impl Display for ProtoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use ProtoError::*;
+        match self {
+            IllegalPackage { package } => write!(f, "Package '{package}' is not a valid Protocol Buffers package name"),
+            IllegalTypeName { name } => write!(f, "Type name '{name}' is not a valid Protocol Buffers identifier"),
+            DuplicateTypeName { name } => write!(f, "Type name '{name}' is given more than once"),
+            IllegalValueName { type_name, name } => write!(f, "Value name '{name}' in enum {type_name} is not a valid Protocol Buffers identifier"),
+            DuplicateValueName { type_name, name } => write!(f, "Value name '{name}' occurs more than once in enum {type_name}"),
+            ReservedValueName { type_name, name } => write!(f, "Value name '{name}' is reserved in enum {type_name}"),
+            NoFreeNumber { type_name, name } => write!(f, "No unreserved number is left for value '{name}' in enum {type_name}"),
+            IllegalPrevious { reason } => write!(f, "Failed to parse previous .proto file: {reason}"),
+            File { err } => write!(f, "{err}"),
+        }
+    }
+}
+impl Error for ProtoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use ProtoError::*;
+        match self {
+            File { err } => err.source(),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Splits the contents of a `.proto` file into tokens, dropping comments.
+///
+/// # Arguments
+/// - `contents`: The contents to split.
+///
+/// # Returns
+/// A list of [`Token`]s.
+///
+/// # Errors
+/// This function errors if a string literal or block comment is not terminated.
+fn tokenize(contents: &str) -> Result<Vec<Token>, ProtoError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            },
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev: char = '\0';
+                loop {
+                    match chars.next() {
+                        Some('/') if prev == '*' => break,
+                        Some(c) => prev = c,
+                        None => return Err(ProtoError::IllegalPrevious { reason: "Unterminated block comment".into() }),
+                    }
+                }
+            },
+            '"' | '\'' => {
+                let mut value: String = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => value.extend(chars.next()),
+                        Some(end) if end == c => break,
+                        Some(c) => value.push(c),
+                        None => return Err(ProtoError::IllegalPrevious { reason: "Unterminated string literal".into() }),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            },
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' || c == '+' => {
+                let mut word: String = c.into();
+                while let Some(c) = chars.peek() {
+                    if !(c.is_alphanumeric() || *c == '_' || *c == '.' || *c == '-' || *c == '+') {
+                        break;
+                    }
+                    word.push(*c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            },
+            c if c.is_whitespace() => {},
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses a number in a `.proto` file.
+///
+/// # Arguments
+/// - `word`: The word to parse.
+///
+/// # Returns
+/// The parsed number.
+///
+/// # Errors
+/// This function errors if `word` is not a (decimal, hexadecimal or octal) 32-bit integer.
+fn parse_number(word: &str) -> Result<i32, ProtoError> {
+    let (negative, digits): (bool, &str) = match word.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, word),
+    };
+    let value: Result<i64, _> = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8)
+    } else {
+        digits.parse()
+    };
+    value
+        .ok()
+        .and_then(|value| i32::try_from(if negative { -value } else { value }).ok())
+        .ok_or_else(|| ProtoError::IllegalPrevious { reason: format!("'{word}' is not a valid enum number") })
+}
+
+/// Parses the enums in the contents of a `.proto` file.
+///
+/// Only value definitions and `reserved` statements are parsed; any options are ignored.
+///
+/// # Arguments
+/// - `contents`: The contents of the file.
+///
+/// # Returns
+/// A list of pairs of enum names and their [`PreviousEnum`]s.
+///
+/// # Errors
+/// This function errors if the file is not a valid `.proto` file.
+fn parse_previous(contents: &str) -> Result<Vec<(String, PreviousEnum)>, ProtoError> {
+    let tokens: Vec<Token> = tokenize(contents)?;
+    let mut res: Vec<(String, PreviousEnum)> = Vec::new();
+    let mut i: usize = 0;
+    while i < tokens.len() {
+        // Find the start of the next enum
+        let name: &str = match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (Token::Word(keyword), Some(Token::Word(name)), Some(Token::Punct('{'))) if keyword == "enum" => name,
+            _ => {
+                i += 1;
+                continue;
+            },
+        };
+        i += 3;
+
+        // Parse its statements
+        let mut prev: PreviousEnum = PreviousEnum { values: Vec::new(), reserved_numbers: Vec::new(), reserved_names: Vec::new() };
+        loop {
+            let start: usize = i;
+            while i < tokens.len() && tokens[i] != Token::Punct(';') && tokens[i] != Token::Punct('}') {
+                i += 1;
+            }
+            if i >= tokens.len() {
+                return Err(ProtoError::IllegalPrevious { reason: format!("Unterminated enum {name}") });
+            }
+            let stmt: &[Token] = &tokens[start..i];
+            match stmt {
+                [] => {},
+                [Token::Word(keyword), list @ ..] if keyword == "reserved" => {
+                    for item in list.split(|t| *t == Token::Punct(',')) {
+                        match item {
+                            [Token::Str(name)] => prev.reserved_names.push(name.clone()),
+                            [Token::Word(n)] => {
+                                let n: i32 = parse_number(n)?;
+                                prev.reserved_numbers.push((n, n));
+                            },
+                            [Token::Word(from), Token::Word(to), Token::Word(until)] if to == "to" => {
+                                let until: i32 = if until == "max" { i32::MAX } else { parse_number(until)? };
+                                prev.reserved_numbers.push((parse_number(from)?, until));
+                            },
+                            _ => return Err(ProtoError::IllegalPrevious { reason: format!("Illegal reserved statement in enum {name}") }),
+                        }
+                    }
+                },
+                [Token::Word(keyword), ..] if keyword == "option" => {},
+                [Token::Word(value), Token::Punct('='), Token::Word(n), ..] => prev.values.push((value.clone(), parse_number(n)?)),
+                _ => return Err(ProtoError::IllegalPrevious { reason: format!("Illegal statement in enum {name}") }),
+            }
+            i += 1;
+            if tokens[i - 1] == Token::Punct('}') {
+                break;
+            }
+        }
+        res.push((name.into(), prev));
+    }
+    Ok(res)
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// A token in a `.proto` file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    /// An identifier, keyword or number.
+    Word(String),
+    /// A string literal, without quotes.
+    Str(String),
+    /// Any other character.
+    Punct(char),
+}
+
+/// An enum as it is defined in a previous `.proto` file.
+#[derive(Clone, Debug)]
+struct PreviousEnum {
+    /// The names and numbers of the values.
+    values: Vec<(String, i32)>,
+    /// The ranges of reserved numbers (both inclusive).
+    reserved_numbers: Vec<(i32, i32)>,
+    /// The reserved names.
+    reserved_names: Vec<String>,
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Generates a `.proto` file with enums that mirror the variants of [`EnumDebug`] types.
+///
+/// Every type becomes an enum named after [`EnumDebug::type_name()`]. Its values are the
+/// [`EnumDebug::variant_names()`] in SCREAMING_SNAKE_CASE, prefixed by the type name in
+/// SCREAMING_SNAKE_CASE and preceded by a `<TYPE>_UNSPECIFIED = 0` value.
+///
+/// See the [module-level documentation](self) for examples.
+#[derive(Clone, Debug, Default)]
+pub struct ProtoGenerator {
+    /// The package of the generated file, if any.
+    package: Option<String>,
+    /// Whether to reserve the numbers and names of removed values.
+    reserve_removed: bool,
+    /// The type names and variant names of the types to generate.
+    types: Vec<(&'static str, &'static [&'static str])>,
+}
+impl ProtoGenerator {
+    /// Constructor for the ProtoGenerator.
+    ///
+    /// # Returns
+    /// A new ProtoGenerator without a package or any types.
+    #[inline]
+    pub fn new() -> Self { Self { package: None, reserve_removed: false, types: Vec::new() } }
+
+    /// Sets the package of the generated file.
+    ///
+    /// # Arguments
+    /// - `package`: The name of the package, e.g., `jedi.v1`.
+    ///
+    /// # Returns
+    /// The same ProtoGenerator, for chaining.
+    #[inline]
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// Reserves the numbers and names of values that exist in the previous file but no longer in
+    /// the types.
+    ///
+    /// This prevents them from being reused by later versions of the file.
+    ///
+    /// # Returns
+    /// The same ProtoGenerator, for chaining.
+    #[inline]
+    pub fn reserve_removed(mut self) -> Self {
+        self.reserve_removed = true;
+        self
+    }
+
+    /// Adds an [`EnumDebug`] type to generate.
+    ///
+    /// Types are generated in the order in which they are added.
+    ///
+    /// # Returns
+    /// The same ProtoGenerator, for chaining.
+    #[inline]
+    pub fn add<T: ?Sized + EnumDebug>(mut self) -> Self {
+        self.types.push((T::type_name(), T::variant_names()));
+        self
+    }
+
+
+
+    /// Generates the `.proto` file.
+    ///
+    /// # Arguments
+    /// - `previous`: The contents of the previous version of the file, if any. Values that
+    ///   occur in there keep their number, and new values get the smallest numbers above them
+    ///   that aren't reserved.
+    ///
+    /// # Returns
+    /// The contents of the file.
+    ///
+    /// # Errors
+    /// This function errors if the package is not a valid package name, if the type name of a type
+    /// or the value name of a variant is not a valid identifier (e.g., because the type name is a
+    /// path), if two types have the same type name, if two variants map to the same value name
+    /// (which must be unique across all types in the file), if a new variant maps to a reserved value name, if no unreserved number is left for a
+    /// new variant or if `previous` failed to parse.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::proto::ProtoGenerator;
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     Ahsoka,
+    ///     Yoda,
+    /// }
+    ///
+    /// let previous = r#"
+    /// enum Jedi {
+    ///   JEDI_UNSPECIFIED = 0;
+    ///   JEDI_OBI_WAN_KENOBI = 1;
+    ///   reserved 2 to 3, 5;
+    ///   reserved "JEDI_ANAKIN_SKYWALKER", "JEDI_MACE_WINDU", "JEDI_ANAKIN_SKYWALKER";
+    /// }
+    /// "#;
+    /// assert_eq!(
+    ///     ProtoGenerator::new().add::<Jedi>().generate(Some(previous)).unwrap(),
+    ///     r#"// Generated by enum-debug. Do not edit this file manually.
+    /// syntax = "proto3";
+    ///
+    /// enum Jedi {
+    ///   JEDI_UNSPECIFIED = 0;
+    ///   JEDI_OBI_WAN_KENOBI = 1;
+    ///   JEDI_AHSOKA = 4;
+    ///   JEDI_YODA = 6;
+    ///   reserved 2 to 3, 5;
+    ///   reserved "JEDI_ANAKIN_SKYWALKER", "JEDI_MACE_WINDU";
+    /// }
+    /// "#
+    /// );
+    ///
+    /// // Numbers reserved up to `max` leave no room for new values
+    /// let previous = "enum Jedi { JEDI_UNSPECIFIED = 0; JEDI_OBI_WAN_KENOBI = 1; reserved 2 to max; }";
+    /// assert!(ProtoGenerator::new().add::<Jedi>().generate(Some(previous)).is_err());
+    ///
+    /// // Packages and value names must be valid identifiers
+    /// assert!(ProtoGenerator::new().package("jedi..v1").add::<Jedi>().generate(None).is_err());
+    /// #[derive(EnumDebug)]
+    /// enum Order {
+    ///     Jedi,
+    ///     Padawän,
+    /// }
+    /// assert!(ProtoGenerator::new().add::<Order>().generate(None).is_err());
+    /// ```
+    pub fn generate(&self, previous: Option<&str>) -> Result<String, ProtoError> {
+        let previous: Vec<(String, PreviousEnum)> = match previous {
+            Some(previous) => parse_previous(previous)?,
+            None => Vec::new(),
+        };
+
+        let mut res: String = format!("// {HEADER}\nsyntax = \"proto3\";\n");
+        if let Some(package) = &self.package {
+            if !package.split('.').all(|part| is_identifier(part, &[], &['_'])) {
+                return Err(ProtoError::IllegalPackage { package: package.clone() });
+            }
+            write!(res, "\npackage {package};\n").unwrap();
+        }
+        // NOTE: Values are scoped to the package instead of their enum, so they must be unique across all types
+        let mut values: Vec<String> = Vec::new();
+        for (i, (type_name, variants)) in self.types.iter().enumerate() {
+            if !is_identifier(type_name, &[], &['_']) {
+                return Err(ProtoError::IllegalTypeName { name: type_name });
+            }
+            if self.types[..i].iter().any(|(other, _)| other == type_name) {
+                return Err(ProtoError::DuplicateTypeName { name: type_name });
+            }
+            let prev: Option<&PreviousEnum> = previous.iter().find(|(name, _)| name == type_name).map(|(_, prev)| prev);
+
+            // Name the values
+            let prefix: String = screaming_snake_case(type_name);
+            let unspecified: String = format!("{prefix}_UNSPECIFIED");
+            if values.contains(&unspecified) {
+                return Err(ProtoError::DuplicateValueName { type_name, name: unspecified });
+            }
+            values.push(unspecified.clone());
+            let mut names: Vec<String> = Vec::with_capacity(variants.len());
+            for variant in variants.iter() {
+                let name: String = format!("{prefix}_{}", screaming_snake_case(variant));
+                if !is_identifier(&name, &[], &['_']) {
+                    return Err(ProtoError::IllegalValueName { type_name, name });
+                }
+                if values.contains(&name) {
+                    return Err(ProtoError::DuplicateValueName { type_name, name });
+                }
+                values.push(name.clone());
+                names.push(name);
+            }
+
+            // Number them
+            let mut numbers: Vec<i32> = Vec::with_capacity(variants.len());
+            let mut reserved_numbers: Vec<(i32, i32)> = Vec::new();
+            let mut reserved_names: Vec<String> = Vec::new();
+            if let Some(prev) = prev {
+                let mut next: i32 = prev.values.iter().map(|(_, n)| *n).max().unwrap_or(0);
+                for name in &names {
+                    match prev.values.iter().find(|(other, _)| other == name) {
+                        Some((_, n)) => numbers.push(*n),
+                        None if prev.reserved_names.contains(name) => {
+                            return Err(ProtoError::ReservedValueName { type_name, name: name.clone() });
+                        },
+                        None => {
+                            // Find the smallest number above the ones used so far that isn't reserved
+                            loop {
+                                next = next.checked_add(1).ok_or_else(|| ProtoError::NoFreeNumber { type_name, name: name.clone() })?;
+                                match prev.reserved_numbers.iter().find(|(from, until)| (*from..=*until).contains(&next)) {
+                                    Some((_, until)) => next = *until,
+                                    None => break,
+                                }
+                            }
+                            numbers.push(next);
+                        },
+                    }
+                }
+                reserved_numbers.extend(prev.reserved_numbers.iter().copied());
+                reserved_names.extend(prev.reserved_names.iter().cloned());
+                if self.reserve_removed {
+                    for (name, n) in &prev.values {
+                        if *name != unspecified && !names.contains(name) {
+                            reserved_numbers.push((*n, *n));
+                            reserved_names.push(name.clone());
+                        }
+                    }
+                }
+                reserved_numbers.sort();
+                reserved_numbers.dedup();
+                let mut seen: HashSet<String> = HashSet::new();
+                reserved_names.retain(|name| seen.insert(name.clone()));
+            } else {
+                numbers.extend((1..=variants.len()).map(|n| n as i32));
+            }
+
+            // Write the enum
+            write!(res, "\nenum {type_name} {{\n  {unspecified} = 0;\n").unwrap();
+            for (name, n) in names.iter().zip(numbers) {
+                writeln!(res, "  {name} = {n};").unwrap();
+            }
+            if !reserved_numbers.is_empty() {
+                res.push_str("  reserved ");
+                for (j, (from, until)) in reserved_numbers.into_iter().enumerate() {
+                    if j > 0 {
+                        res.push_str(", ");
+                    }
+                    match (from, until) {
+                        (from, until) if from == until => write!(res, "{from}").unwrap(),
+                        (from, i32::MAX) => write!(res, "{from} to max").unwrap(),
+                        (from, until) => write!(res, "{from} to {until}").unwrap(),
+                    }
+                }
+                res.push_str(";\n");
+            }
+            if !reserved_names.is_empty() {
+                res.push_str("  reserved ");
+                for (j, name) in reserved_names.into_iter().enumerate() {
+                    if j > 0 {
+                        res.push_str(", ");
+                    }
+                    write!(res, "\"{name}\"").unwrap();
+                }
+                res.push_str(";\n");
+            }
+            res.push_str("}\n");
+        }
+        Ok(res)
+    }
+
+    /// Generates the `.proto` file and writes it to the given path.
+    ///
+    /// If the file already exists, it is used as the previous version of the file (see
+    /// [`ProtoGenerator::generate()`]).
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to write.
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the existing file, to
+    /// [generate](ProtoGenerator::generate()) the new file or to write it.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), ProtoError> {
+        let path: &Path = path.as_ref();
+        let previous: Option<String> = read_generated(path).map_err(|err| ProtoError::File { err })?;
+        let contents: String = self.generate(previous.as_deref())?;
+        write_generated(path, &contents).map_err(|err| ProtoError::File { err })
+    }
+
+    /// Checks that the file at the given path is up-to-date.
+    ///
+    /// The file is used as the previous version of itself (see [`ProtoGenerator::generate()`]),
+    /// so numbers assigned by an earlier [`ProtoGenerator::write()`] are respected.
+    ///
+    /// **Note**: because the numbers are taken from the file itself, this does _not_ detect
+    /// numbers that were changed by hand (e.g., from `= 2` to `= 3`). Use
+    /// [`ProtoGenerator::check_against()`] with a trusted previous version of the file (e.g., the
+    /// one on the main branch) to detect those as well.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to check.
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the existing file, to
+    /// [generate](ProtoGenerator::generate()) the file, or if its contents differ from what would
+    /// be generated.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::proto::ProtoGenerator;
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// let path = std::env::temp_dir().join("enum-debug-proto-doctest.proto");
+    /// # let _ = std::fs::remove_file(&path);
+    /// let generator = ProtoGenerator::new().add::<Jedi>();
+    /// generator.write(&path).unwrap();
+    /// generator.check(&path).unwrap();
+    ///
+    /// // NOTE: Numbers changed by hand are taken as the previous numbering, so they pass
+    /// std::fs::write(&path, std::fs::read_to_string(&path).unwrap().replace(" = 2;", " = 3;")).unwrap();
+    /// generator.check(&path).unwrap();
+    ///
+    /// std::fs::write(&path, "enum Jedi { JEDI_UNSPECIFIED = 0; }").unwrap();
+    /// assert!(generator.check(&path).is_err());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), ProtoError> {
+        let path: &Path = path.as_ref();
+        let existing: Option<String> = read_generated(path).map_err(|err| ProtoError::File { err })?;
+        self.check_against(path, existing.as_deref())
+    }
+
+    /// Checks that the file at the given path is what would be generated from the given previous
+    /// version of the file.
+    ///
+    /// Unlike [`ProtoGenerator::check()`], this detects numbers that were changed by hand in the
+    /// checked file.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to check.
+    /// - `previous`: The contents of the previous version of the file, if any (see
+    ///   [`ProtoGenerator::generate()`]).
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](ProtoGenerator::generate()) the file, to
+    /// read the existing file, or if its contents differ from what would be generated.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::proto::ProtoGenerator;
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// let path = std::env::temp_dir().join("enum-debug-proto-against-doctest.proto");
+    /// let generator = ProtoGenerator::new().add::<Jedi>();
+    /// generator.write(&path).unwrap();
+    /// let previous: String = std::fs::read_to_string(&path).unwrap();
+    /// generator.check_against(&path, Some(&previous)).unwrap();
+    ///
+    /// std::fs::write(&path, previous.replace(" = 2;", " = 3;")).unwrap();
+    /// assert!(generator.check_against(&path, Some(&previous)).is_err());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn check_against(&self, path: impl AsRef<Path>, previous: Option<&str>) -> Result<(), ProtoError> {
+        let contents: String = self.generate(previous)?;
+        check_generated(path.as_ref(), &contents).map_err(|err| ProtoError::File { err })
+    }
+}
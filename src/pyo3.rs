@@ -0,0 +1,330 @@
+//  PYO3.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:49:24
+//  Last edited:
+//    19 Oct 2026, 01:21:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `pyo3` for [`EnumDebug`] types.
+//!
+//!   Fieldless enums can be exposed to Python as an [`enum.Enum`](https://docs.python.org/3/library/enum.html)
+//!   subclass through the [`EnumDebug`](derive@crate::EnumDebug)-derive macro
+//!   by adding `#[enum_debug(pyo3)]`. The class is named after
+//!   [`EnumDebug::type_name()`] and has a member for every name in
+//!   [`EnumDebug::variant_names()`], whose value is that same name. The
+//!   derive macro also implements [`PyEnum`], `IntoPyObject` and
+//!   `FromPyObject`, so values convert to and from members of the class:
+//!   ```rust
+//!   use enum_debug::EnumDebug;
+//!   use pyo3::prelude::*;
+//!   use pyo3::types::PyDict;
+//!
+//!   #[derive(Debug, EnumDebug, PartialEq)]
+//!   #[enum_debug(pyo3)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   Python::attach(|py| {
+//!       let module = PyModule::new(py, "jedi").unwrap();
+//!       enum_debug::pyo3::add_enum::<Jedi>(&module).unwrap();
+//!
+//!       let locals = PyDict::new(py);
+//!       locals.set_item("jedi", &module).unwrap();
+//!       locals.set_item("padawan", Jedi::AnakinSkywalker).unwrap();
+//!       py.run(c"assert padawan is jedi.Jedi.AnakinSkywalker", None, Some(&locals)).unwrap();
+//!       py.run(c"assert [m.value for m in jedi.Jedi] == ['ObiWanKenobi', 'AnakinSkywalker']", None, Some(&locals)).unwrap();
+//!
+//!       let master: Jedi = py.eval(c"jedi.Jedi('ObiWanKenobi')", None, Some(&locals)).unwrap().extract().unwrap();
+//!       assert_eq!(master, Jedi::ObiWanKenobi);
+//!       let err = py.eval(c"'ObiWanKenobi'", None, Some(&locals)).unwrap().extract::<Jedi>().unwrap_err();
+//!       assert_eq!(err.to_string(), "TypeError: Expected a member of Jedi, got 'str'");
+//!   });
+//!   ```
+//!
+//!   Use [`PyiGenerator`] to generate `.pyi` stubs for the classes.
+//!
+//!   This module is only available when the `pyo3`-feature is used.
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::path::Path;
+
+use ::pyo3::exceptions::{PyTypeError, PyValueError};
+use ::pyo3::types::{PyAnyMethods as _, PyModule, PyModuleMethods as _, PyType, PyTypeMethods as _};
+use ::pyo3::{Borrowed, Bound, Py, PyAny, PyErr, PyResult, Python};
+
+use crate::generated::{check_generated, is_identifier, write_generated, GeneratedFileError, HEADER};
+use crate::{EnumDebug, FromVariant};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when generating `.pyi` stubs.
+#[derive(Debug)]
+pub enum PyiError {
+    /// The type name of an [`EnumDebug`] type is not a valid Python identifier.
+    IllegalTypeName { name: &'static str },
+    /// A variant name of an [`EnumDebug`] type is not a valid Python identifier.
+    IllegalVariantName { type_name: &'static str, name: &'static str },
+    /// Two types have the same type name.
+    DuplicateTypeName { name: &'static str },
+    /// Failed to write the generated file, or to check an existing one.
+    File { err: GeneratedFileError },
+}
+impl Display for PyiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use PyiError::*;
+        match self {
+            IllegalTypeName { name } => write!(f, "Type name '{name}' is not a valid Python identifier"),
+            IllegalVariantName { type_name, name } => write!(f, "Variant name '{name}' of type {type_name} is not a valid Python identifier"),
+            DuplicateTypeName { name } => write!(f, "Type name '{name}' is given more than once"),
+            File { err } => write!(f, "{err}"),
+        }
+    }
+}
+impl Error for PyiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use PyiError::*;
+        match self {
+            File { err } => err.source(),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Exposes a fieldless [`EnumDebug`] type as a Python `enum.Enum` subclass.
+///
+/// Derive this with `#[enum_debug(pyo3)]`, which creates the class once per interpreter.
+pub trait PyEnum: FromVariant {
+    /// Returns the Python class of this type.
+    ///
+    /// # Arguments
+    /// - `py`: A token proving we're attached to the Python interpreter.
+    ///
+    /// # Returns
+    /// The `enum.Enum` subclass, as created by [`create_class()`].
+    ///
+    /// # Errors
+    /// This function errors if we failed to create the class.
+    fn py_class(py: Python<'_>) -> PyResult<&Bound<'_, PyType>>;
+}
+
+
+
+/// Creates a new Python `enum.Enum` subclass for an [`EnumDebug`] type.
+///
+/// Used by the implementation derived with `#[enum_debug(pyo3)]`. Every call creates a new
+/// class, so prefer [`PyEnum::py_class()`].
+///
+/// # Arguments
+/// - `py`: A token proving we're attached to the Python interpreter.
+///
+/// # Returns
+/// A class named after [`EnumDebug::type_name()`] with a member for every name in
+/// [`EnumDebug::variant_names()`], whose value is that same name.
+///
+/// # Errors
+/// This function errors if Python failed to create the class, e.g., because a variant name is
+/// reserved by `enum.Enum`.
+pub fn create_class<T: EnumDebug>(py: Python<'_>) -> PyResult<Py<PyType>> {
+    let members: Vec<(&'static str, &'static str)> = T::variants().map(|name| (name, name)).collect();
+    let class: Bound<PyAny> = py.import("enum")?.getattr("Enum")?.call1((T::type_name(), members))?;
+    Ok(class.cast_into::<PyType>()?.unbind())
+}
+
+/// Adds the Python class of a [`PyEnum`] type to a Python module.
+///
+/// The class is added under its [type name](EnumDebug::type_name()), and its `__module__` is set
+/// to the name of the module (which allows members to be pickled).
+///
+/// # Arguments
+/// - `module`: The [`PyModule`] to add the class to.
+///
+/// # Errors
+/// This function errors if we failed to create the class or to add it to the module.
+pub fn add_enum<T: PyEnum>(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    let class: &Bound<PyType> = T::py_class(module.py())?;
+    class.setattr("__module__", module.name()?)?;
+    module.add(<T as EnumDebug>::type_name(), class)
+}
+
+/// Converts a value of a [`PyEnum`] type to a member of its Python class.
+///
+/// Used by the implementation of `IntoPyObject` derived with `#[enum_debug(pyo3)]`.
+///
+/// # Arguments
+/// - `py`: A token proving we're attached to the Python interpreter.
+/// - `value`: The value to convert.
+///
+/// # Returns
+/// The member of the class with the variant name of `value`.
+///
+/// # Errors
+/// This function errors if we failed to create the class or to find the member.
+pub fn to_python<'py, T: PyEnum>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> { T::py_class(py)?.call1((value.variant_name(),)) }
+
+/// Converts a member of the Python class of a [`PyEnum`] type to a value of that type.
+///
+/// Used by the implementation of `FromPyObject` derived with `#[enum_debug(pyo3)]`.
+///
+/// # Arguments
+/// - `obj`: The Python object to convert.
+///
+/// # Returns
+/// The variant with the name of the member's value.
+///
+/// # Errors
+/// This function errors with a `TypeError` if `obj` is not a member of the class, or with a
+/// `ValueError` if its value is not the name of a variant.
+pub fn from_python<T: PyEnum>(obj: Borrowed<'_, '_, PyAny>) -> PyResult<T> {
+    let class: &Bound<PyType> = T::py_class(obj.py())?;
+    if !obj.is_instance(class)? {
+        return Err(PyTypeError::new_err(format!("Expected a member of {}, got '{}'", <T as EnumDebug>::type_name(), obj.get_type().name()?)));
+    }
+    let name: String = obj.getattr("value")?.extract()?;
+    T::from_variant_name(&name).map_err(|err| PyErr::new::<PyValueError, _>(err.to_string()))
+}
+
+
+
+/// Returns the description of the variant at the given index, like [`EnumDebug::variant_description()`].
+type DescriptionFn = fn(usize) -> Option<&'static str>;
+
+/// Generates `.pyi` stubs for the Python classes of [`EnumDebug`] types.
+///
+/// Every type becomes an `enum.Enum` subclass, matching the classes created by
+/// [`create_class()`]. Variant descriptions are added as docstrings.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::pyo3::PyiGenerator;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// #[enum_debug(pyo3)]
+/// enum Jedi {
+///     /// The master of Anakin Skywalker.
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// assert_eq!(
+///     PyiGenerator::new().add::<Jedi>().generate().unwrap(),
+///     r#"# Generated by enum-debug. Do not edit this file manually.
+/// import enum
+///
+/// class Jedi(enum.Enum):
+///     ObiWanKenobi = "ObiWanKenobi"
+///     """The master of Anakin Skywalker."""
+///     AnakinSkywalker = "AnakinSkywalker"
+/// "#
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PyiGenerator {
+    /// The type names, variant names and [`EnumDebug::variant_description()`]s of the types to
+    /// generate.
+    types: Vec<(&'static str, &'static [&'static str], DescriptionFn)>,
+}
+impl PyiGenerator {
+    /// Constructor for the PyiGenerator.
+    ///
+    /// # Returns
+    /// A new PyiGenerator without any types.
+    #[inline]
+    pub fn new() -> Self { Self { types: Vec::new() } }
+
+    /// Adds an [`EnumDebug`] type to generate.
+    ///
+    /// Types are generated in the order in which they are added.
+    ///
+    /// # Returns
+    /// The same PyiGenerator, for chaining.
+    #[inline]
+    pub fn add<T: ?Sized + EnumDebug>(mut self) -> Self {
+        self.types.push((T::type_name(), T::variant_names(), T::variant_description));
+        self
+    }
+
+
+
+    /// Generates the `.pyi` stubs.
+    ///
+    /// # Returns
+    /// The contents of the stub file.
+    ///
+    /// # Errors
+    /// This function errors if the type name or a variant name of a type is not a valid Python
+    /// identifier, or if two types have the same type name.
+    pub fn generate(&self) -> Result<String, PyiError> {
+        let mut res: String = format!("# {HEADER}\nimport enum\n");
+        for (i, (type_name, variants, descriptions)) in self.types.iter().enumerate() {
+            if !is_identifier(type_name, &['_'], &['_']) {
+                return Err(PyiError::IllegalTypeName { name: type_name });
+            }
+            if self.types[..i].iter().any(|(other, _, _)| other == type_name) {
+                return Err(PyiError::DuplicateTypeName { name: type_name });
+            }
+
+            write!(res, "\nclass {type_name}(enum.Enum):\n").unwrap();
+            if variants.is_empty() {
+                res.push_str("    pass\n");
+            }
+            for (j, name) in variants.iter().enumerate() {
+                if !is_identifier(name, &['_'], &['_']) {
+                    return Err(PyiError::IllegalVariantName { type_name, name });
+                }
+                writeln!(res, "    {name} = \"{name}\"").unwrap();
+                if let Some(desc) = descriptions(j) {
+                    writeln!(res, "    \"\"\"{}\"\"\"", desc.replace('\\', "\\\\").replace('"', "\\\"")).unwrap();
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    /// Generates the `.pyi` stubs and writes them to the given path.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to write. Any existing file is overwritten.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](PyiGenerator::generate()) the stubs or to
+    /// write them.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), PyiError> {
+        let contents: String = self.generate()?;
+        write_generated(path.as_ref(), &contents).map_err(|err| PyiError::File { err })
+    }
+
+    /// Checks that the stubs at the given path are up-to-date.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to check.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](PyiGenerator::generate()) the stubs, to
+    /// read the existing file, or if its contents differ from what would be generated.
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), PyiError> {
+        let contents: String = self.generate()?;
+        check_generated(path.as_ref(), &contents).map_err(|err| PyiError::File { err })
+    }
+}
+
+
+
+/// Re-exports used by the implementation derived with `#[enum_debug(pyo3)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::pyo3;
+}
@@ -0,0 +1,99 @@
+//  RUSQLITE.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:54:57
+//  Last edited:
+//    18 Oct 2026, 23:59:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `rusqlite` for [`EnumDebug`] types.
+//!
+//!   Fieldless enums can be stored in TEXT columns through the
+//!   [`EnumDebug`](derive@crate::EnumDebug)-derive macro by adding
+//!   `#[enum_debug(rusqlite)]`. This implements `ToSql`, which writes
+//!   [`EnumDebug::variant_name()`], and `FromSql`, which parses the name back
+//!   with [`FromVariant::from_variant_name()`]. Names that do not match any
+//!   variant fail to decode with an [`UnknownVariantError`](crate::UnknownVariantError) listing
+//!   [`EnumDebug::variant_names()`]:
+//!   ```rust
+//!   use enum_debug::sql::check_constraint;
+//!   use enum_debug::{EnumDebug, UnknownVariantError};
+//!   use rusqlite::Connection;
+//!
+//!   #[derive(Debug, EnumDebug, PartialEq)]
+//!   #[enum_debug(rusqlite)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   let conn = Connection::open_in_memory().unwrap();
+//!   conn.execute_batch("CREATE TABLE jedi (name TEXT NOT NULL, master TEXT)").unwrap();
+//!   conn.execute("INSERT INTO jedi VALUES (?1, ?2)", ("Anakin", Jedi::ObiWanKenobi)).unwrap();
+//!   conn.execute("INSERT INTO jedi VALUES (?1, ?2)", ("Luke", "DarthVader")).unwrap();
+//!
+//!   let master: Jedi = conn.query_row("SELECT master FROM jedi WHERE name = 'Anakin'", (), |row| row.get(0)).unwrap();
+//!   assert_eq!(master, Jedi::ObiWanKenobi);
+//!   let raw: String = conn.query_row("SELECT master FROM jedi WHERE name = 'Anakin'", (), |row| row.get(0)).unwrap();
+//!   assert_eq!(raw, "ObiWanKenobi");
+//!
+//!   let err = conn.query_row("SELECT master FROM jedi WHERE name = 'Luke'", (), |row| row.get::<_, Jedi>(0)).unwrap_err();
+//!   let rusqlite::Error::FromSqlConversionFailure(_, _, err) = err else { panic!("Unexpected error: {err}") };
+//!   assert_eq!(err.downcast_ref::<UnknownVariantError>(), Some(&UnknownVariantError::new::<Jedi>("DarthVader")));
+//!   assert_eq!(err.to_string(), "Unknown variant 'DarthVader' for type Jedi (expected one of: 'ObiWanKenobi', 'AnakinSkywalker')");
+//!
+//!   // The database can also be told to reject unknown names upfront
+//!   conn.execute_batch(&format!("CREATE TABLE padawans (master TEXT {})", check_constraint::<Jedi>("master"))).unwrap();
+//!   conn.execute("INSERT INTO padawans VALUES (?1)", (Jedi::AnakinSkywalker,)).unwrap();
+//!   assert!(conn.execute("INSERT INTO padawans VALUES (?1)", ("DarthVader",)).is_err());
+//!   ```
+//!
+//!   This module is only available when the `rusqlite`-feature is used.
+//
+
+use ::rusqlite::types::{FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+
+use crate::{EnumDebug, FromVariant};
+
+
+/***** LIBRARY *****/
+/// Converts a variant of an [`EnumDebug`] type to an SQLite value.
+///
+/// Used by the implementation derived with `#[enum_debug(rusqlite)]`.
+///
+/// # Arguments
+/// - `value`: The value to convert.
+///
+/// # Returns
+/// A TEXT value with the [`EnumDebug::variant_name()`] of the variant.
+#[inline]
+pub fn to_sql<T: ?Sized + EnumDebug>(value: &T) -> ToSqlOutput<'static> { ToSqlOutput::Borrowed(ValueRef::Text(value.variant_name().as_bytes())) }
+
+/// Parses an SQLite value as a variant of an [`EnumDebug`] type.
+///
+/// Used by the implementation derived with `#[enum_debug(rusqlite)]`.
+///
+/// # Arguments
+/// - `value`: The [`ValueRef`] to parse.
+///
+/// # Returns
+/// The variant whose name matches the given value.
+///
+/// # Errors
+/// This function errors with [`FromSqlError::InvalidType`] if the value is not TEXT, or with an
+/// [`UnknownVariantError`](crate::UnknownVariantError) wrapped in [`FromSqlError::Other`] if it does not match any variant.
+pub fn column_result<T: FromVariant>(value: ValueRef<'_>) -> FromSqlResult<T> {
+    let name: &str = value.as_str()?;
+    T::from_variant_name(name).map_err(FromSqlError::other)
+}
+
+
+
+/// Re-exports used by the implementation derived with `#[enum_debug(rusqlite)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::rusqlite;
+}
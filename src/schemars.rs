@@ -0,0 +1,118 @@
+//  SCHEMARS.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:35:11
+//  Last edited:
+//    18 Oct 2026, 23:36:27
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements JSON Schema generation with `schemars` for values that are
+//!   written as the names of [`EnumDebug`] variants.
+//!
+//!   Fieldless enums can implement [`JsonSchema`] through the
+//!   [`EnumDebug`](derive@crate::EnumDebug)-derive macro by adding
+//!   `#[enum_debug(json_schema)]`. Only do so if the enum is also
+//!   (de)serialized as its variant names, e.g., because serde's derive does so
+//!   for fieldless enums or because `#[enum_debug(follow_serde)]` is used.
+//!   ```rust
+//!   use enum_debug::EnumDebug;
+//!   use schemars::JsonSchema;
+//!
+//!   #[derive(EnumDebug)]
+//!   #[enum_debug(json_schema)]
+//!   enum Mode {
+//!       /// Runs as fast as possible.
+//!       Fast,
+//!       Safe,
+//!   }
+//!
+//!   #[derive(JsonSchema)]
+//!   struct Config {
+//!       mode: Mode,
+//!   }
+//!
+//!   let schema = schemars::schema_for!(Config);
+//!   assert_eq!(
+//!       schema.get("$defs").unwrap().to_string(),
+//!       r#"{"Mode":{"oneOf":[{"const":"Fast","description":"Runs as fast as possible."},{"const":"Safe"}],"title":"Mode","type":"string"}}"#
+//!   );
+//!   ```
+//!
+//!   For other types, such as enums whose variants carry values but are
+//!   serialized using `enum_debug::serde::name_only`, use
+//!   [`name_schema()`] with `#[schemars(schema_with = "...")]`.
+//!
+//!   This module is only available when the `schemars`-feature is used.
+//
+
+pub use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::EnumDebug;
+
+
+/***** LIBRARY *****/
+/// Generates a JSON Schema for the names of the variants of an [`EnumDebug`] type.
+///
+/// The schema is a string schema with [`EnumDebug::type_name()`] as title. If no variant has a
+/// [description](EnumDebug::variant_description()), the names are listed as an `enum`.
+/// Otherwise, every name is listed as a `const` in a `oneOf` with its description, if any.
+///
+/// # Arguments
+/// - `generator`: The [`SchemaGenerator`] that generates the schema. Not used, but required by
+///   `#[schemars(schema_with = "...")]`.
+///
+/// # Returns
+/// A new [`Schema`] that accepts any name in [`EnumDebug::variant_names()`].
+///
+/// # Example
+/// ```rust
+/// use enum_debug::EnumDebug;
+/// use schemars::JsonSchema;
+///
+/// #[derive(EnumDebug)]
+/// enum Event {
+///     Connect { id: u32 },
+///     Disconnect { id: u32 },
+/// }
+///
+/// #[derive(JsonSchema)]
+/// struct Filter {
+///     #[schemars(schema_with = "enum_debug::schemars::name_schema::<Event>")]
+///     event: Event,
+/// }
+///
+/// let schema = schemars::schema_for!(Filter);
+/// assert_eq!(
+///     schema.get("properties").unwrap().to_string(),
+///     r#"{"event":{"enum":["Connect","Disconnect"],"title":"Event","type":"string"}}"#
+/// );
+/// ```
+pub fn name_schema<T: EnumDebug>(generator: &mut SchemaGenerator) -> Schema {
+    let _ = generator;
+    let names: &'static [&'static str] = T::variant_names();
+    if (0..names.len()).all(|i| T::variant_description(i).is_none()) {
+        return json_schema!({
+            "title": T::type_name(),
+            "type": "string",
+            "enum": names,
+        });
+    }
+
+    // Otherwise, list the names with their descriptions
+    let one_of: Vec<Schema> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| match T::variant_description(i) {
+            Some(desc) => json_schema!({ "const": name, "description": desc }),
+            None => json_schema!({ "const": name }),
+        })
+        .collect();
+    json_schema!({
+        "title": T::type_name(),
+        "type": "string",
+        "oneOf": one_of,
+    })
+}
@@ -0,0 +1,222 @@
+//  SERDE.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:23:45
+//  Last edited:
+//    18 Oct 2026, 23:24:51
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements adapters for (de)serializing [`EnumDebug`] types by their
+//!   variant names using `serde`.
+//!
+//!   This module is only available when the `serde`-feature is used.
+//
+
+use std::fmt::{Formatter, Result as FResult};
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Serialize, Serializer};
+
+use crate::{EnumDebug, EnumDebugFormatter, FromVariant};
+
+
+/***** AUXILLARY *****/
+/// Visits a string with the (possibly qualified) name of a variant.
+struct NameVisitor<T> {
+    /// Whether the name is qualified with the type name.
+    qualified: bool,
+    /// Remembers the type of which to parse a variant.
+    _t:        PhantomData<fn() -> T>,
+}
+impl<'de, T: FromVariant> Visitor<'de> for NameVisitor<T> {
+    type Value = T;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult {
+        if self.qualified {
+            write!(f, "a variant name of {} prefixed by '{}::'", T::type_name(), T::type_name())
+        } else {
+            write!(f, "a variant name of {}", T::type_name())
+        }
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let name: &str = if self.qualified {
+            match v.strip_prefix(T::type_name()).and_then(|v| v.strip_prefix("::")) {
+                Some(name) => name,
+                None => return Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+            }
+        } else {
+            v
+        };
+        match T::variant_index_of(name).and_then(T::from_variant_index) {
+            Some(value) => Ok(value),
+            None => Err(E::unknown_variant(name, T::variant_names())),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+impl<'a, T: EnumDebug> Serialize for EnumDebugFormatter<'a, T> {
+    /// Serializes the formatter as a string with the variant name.
+    ///
+    /// If the formatter is [qualified](EnumDebugFormatter::qualified()), it is written as
+    /// `Type::Variant` instead.
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_qualified() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_str(self.e.variant_name())
+        }
+    }
+}
+
+
+
+/// (De)serializes an [`EnumDebug`] type as only its variant name.
+///
+/// Use it with `#[serde(with = "enum_debug::serde::name_only")]`. Serializing works on any
+/// [`EnumDebug`] type, as it discards the values carried by the variant. Deserializing is only
+/// possible for types implementing [`FromVariant`], i.e., fieldless enums. Unknown names are
+/// reported with an error that lists [`EnumDebug::variant_names()`].
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::EnumDebug;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(EnumDebug)]
+/// enum Event {
+///     Login { user: String },
+///     Logout { user: String },
+/// }
+///
+/// #[derive(Debug, Deserialize, EnumDebug, PartialEq, Serialize)]
+/// enum Outcome {
+///     Success,
+///     Failure,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct AuditSummary {
+///     #[serde(with = "enum_debug::serde::name_only")]
+///     event:   Event,
+///     outcome: Outcome,
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct AuditReport {
+///     event:   String,
+///     #[serde(with = "enum_debug::serde::name_only")]
+///     outcome: Outcome,
+/// }
+///
+/// let summary = AuditSummary { event: Event::Login { user: "Amy".into() }, outcome: Outcome::Success };
+/// assert_eq!(serde_json::to_string(&summary).unwrap(), r#"{"event":"Login","outcome":"Success"}"#);
+///
+/// let err = serde_json::from_str::<AuditReport>(r#"{"event":"Login","outcome":"Succes"}"#).unwrap_err();
+/// assert_eq!(err.to_string(), "unknown variant `Succes`, expected `Success` or `Failure` at line 1 column 35");
+/// ```
+pub mod name_only {
+    use super::*;
+
+    /// Serializes the given value as its variant name.
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `serializer`: The [`Serializer`] to serialize with.
+    ///
+    /// # Errors
+    /// This function errors if the `serializer` fails to serialize a string.
+    #[inline]
+    pub fn serialize<T: EnumDebug, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.variant().serialize(serializer)
+    }
+
+    /// Deserializes a value from its variant name.
+    ///
+    /// # Arguments
+    /// - `deserializer`: The [`Deserializer`] to deserialize with.
+    ///
+    /// # Returns
+    /// The variant with the deserialized name.
+    ///
+    /// # Errors
+    /// This function errors if the `deserializer` fails to deserialize a string, or if it is not
+    /// the name of a variant of `T`.
+    #[inline]
+    pub fn deserialize<'de, T: FromVariant, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(NameVisitor { qualified: false, _t: PhantomData })
+    }
+}
+
+/// (De)serializes an [`EnumDebug`] type as its variant name prefixed by its type name.
+///
+/// Use it with `#[serde(with = "enum_debug::serde::qualified_name")]`. Values are written as
+/// `Type::Variant`, where `Type` is given by [`EnumDebug::type_name()`]. Like
+/// [`name_only`], deserializing is only possible for types implementing [`FromVariant`].
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::EnumDebug;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, EnumDebug, PartialEq)]
+/// enum Outcome {
+///     Success,
+///     Failure,
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Summary {
+///     #[serde(with = "enum_debug::serde::qualified_name")]
+///     outcome: Outcome,
+/// }
+///
+/// let summary = Summary { outcome: Outcome::Failure };
+/// let json: String = serde_json::to_string(&summary).unwrap();
+/// assert_eq!(json, r#"{"outcome":"Outcome::Failure"}"#);
+/// assert_eq!(serde_json::from_str::<Summary>(&json).unwrap(), summary);
+/// ```
+pub mod qualified_name {
+    use super::*;
+
+    /// Serializes the given value as its qualified variant name.
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `serializer`: The [`Serializer`] to serialize with.
+    ///
+    /// # Errors
+    /// This function errors if the `serializer` fails to serialize a string.
+    #[inline]
+    pub fn serialize<T: EnumDebug, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.variant().qualified().serialize(serializer)
+    }
+
+    /// Deserializes a value from its qualified variant name.
+    ///
+    /// # Arguments
+    /// - `deserializer`: The [`Deserializer`] to deserialize with.
+    ///
+    /// # Returns
+    /// The variant with the deserialized name.
+    ///
+    /// # Errors
+    /// This function errors if the `deserializer` fails to deserialize a string, if it is not
+    /// prefixed with the type name of `T` or if it is not the name of a variant of `T`.
+    #[inline]
+    pub fn deserialize<'de, T: FromVariant, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(NameVisitor { qualified: true, _t: PhantomData })
+    }
+}
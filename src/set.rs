@@ -0,0 +1,459 @@
+//  SET.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:06:09
+//  Last edited:
+//    18 Oct 2026, 23:19:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`VariantSet`], a bitset over the variants of an
+//!   [`EnumDebug`] type.
+//
+
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::{EnumDebug, UnknownVariantError};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Computes the number of words needed to store one bit per variant of `T`.
+///
+/// # Returns
+/// The number of [`u64`]s in a [`VariantSet<T>`].
+#[inline]
+fn n_words<T: EnumDebug>() -> usize { T::variant_names().len().div_ceil(64) }
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Iterates over the variants in a [`VariantSet`] in declaration order.
+///
+/// This iterator is returned by [`VariantSet::iter()`].
+pub struct Iter<'s, T> {
+    /// The set to iterate over.
+    set:   &'s VariantSet<T>,
+    /// The index of the next variant to consider.
+    index: usize,
+}
+impl<'s, T: EnumDebug> Iterator for Iter<'s, T> {
+    type Item = &'static str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let names: &'static [&'static str] = T::variant_names();
+        while self.index < names.len() {
+            let index: usize = self.index;
+            self.index += 1;
+            if self.set.contains_index(index) {
+                return Some(names[index]);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, Some(T::variant_names().len().saturating_sub(self.index))) }
+}
+
+/// Formats a single variant as `Type::Variant`.
+pub(crate) struct QualifiedName<T> {
+    /// The name of the variant to write.
+    name: &'static str,
+    /// Remembers the type of which to write the name.
+    _t:   PhantomData<fn() -> T>,
+}
+impl<T> QualifiedName<T> {
+    /// Constructor for the QualifiedName.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of a variant of `T`.
+    ///
+    /// # Returns
+    /// A new QualifiedName that [`Debug`]-formats `name` with the type name of `T` in front of it.
+    #[inline]
+    pub(crate) fn new(name: &'static str) -> Self { Self { name, _t: PhantomData } }
+}
+impl<T: EnumDebug> Debug for QualifiedName<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}::{}", T::type_name(), self.name) }
+}
+
+/// Formats a single variant as just its name.
+struct UnqualifiedName(&'static str);
+impl Debug for UnqualifiedName {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.0) }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A set of variants of an [`EnumDebug`] type.
+///
+/// The set only stores which variants occur in it, not any values they carry. Internally, it is
+/// a bitset with one bit per entry in [`EnumDebug::variant_names()`], so membership checks and
+/// set operations are cheap.
+///
+/// The [`Debug`]-formatter writes the set as `{Type::Variant, ...}`, whereas the
+/// [`Display`]-formatter omits the type name, as in `{Variant, ...}`. Both are accepted by its
+/// [`FromStr`]-implementation, which parses a comma-separated list of variant names (optionally
+/// surrounded by curly brackets).
+///
+/// # Examples
+/// ```rust
+/// use enum_debug::{EnumDebug, VariantSet};
+///
+/// #[derive(EnumDebug)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+///     MaceWindu,
+///     MasterYoda,
+/// }
+///
+/// let mut set: VariantSet<Jedi> = VariantSet::new();
+/// set.insert(&Jedi::MaceWindu);
+/// set.insert(&Jedi::ObiWanKenobi);
+/// assert!(set.contains(&Jedi::ObiWanKenobi));
+/// assert!(!set.contains(&Jedi::MasterYoda));
+///
+/// // Sets print their variants in declaration order
+/// assert_eq!(format!("{set:?}"), "{Jedi::ObiWanKenobi, Jedi::MaceWindu}");
+/// assert_eq!(format!("{set}"), "{ObiWanKenobi, MaceWindu}");
+///
+/// // ...and can be parsed back
+/// let other: VariantSet<Jedi> = "MaceWindu, MasterYoda".parse().unwrap();
+/// assert_eq!(set.union(&other).iter().collect::<Vec<_>>(), vec!["ObiWanKenobi", "MaceWindu", "MasterYoda"]);
+/// assert_eq!(set.intersection(&other).iter().collect::<Vec<_>>(), vec!["MaceWindu"]);
+/// ```
+pub struct VariantSet<T> {
+    /// One bit per variant, where the `i`th bit is set if the `i`th variant is in the set.
+    bits: Box<[u64]>,
+    /// Remembers the type of which we store variants.
+    _t:   PhantomData<fn() -> T>,
+}
+
+impl<T: EnumDebug> Default for VariantSet<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<T: EnumDebug> VariantSet<T> {
+    /// Constructor for the VariantSet that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new VariantSet without any variants in it.
+    #[inline]
+    pub fn new() -> Self { Self { bits: vec![0; n_words::<T>()].into_boxed_slice(), _t: PhantomData } }
+
+    /// Constructor for the VariantSet that initializes it with all variants of `T`.
+    ///
+    /// # Returns
+    /// A new VariantSet with every variant in [`EnumDebug::variant_names()`] in it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::{EnumDebug, VariantSet};
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum Jedi {
+    ///     ObiWanKenobi,
+    ///     AnakinSkywalker,
+    /// }
+    ///
+    /// assert_eq!(format!("{:?}", VariantSet::<Jedi>::all()), "{Jedi::ObiWanKenobi, Jedi::AnakinSkywalker}");
+    /// ```
+    #[inline]
+    pub fn all() -> Self {
+        let mut set: Self = Self::new();
+        for index in 0..T::variant_names().len() {
+            set.insert_index(index);
+        }
+        set
+    }
+
+
+
+    /// Adds the variant of the given value to the set.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to add the variant.
+    ///
+    /// # Returns
+    /// True if the variant was newly added, or false if it was already in the set.
+    #[inline]
+    pub fn insert(&mut self, value: &T) -> bool { self.insert_index(value.variant_index()) }
+
+    /// Adds the variant with the given index to the set.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// True if the variant was newly added, or false if it was already in the set.
+    ///
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the variants of `T`.
+    #[inline]
+    pub fn insert_index(&mut self, index: usize) -> bool {
+        let n_variants: usize = T::variant_names().len();
+        if index >= n_variants {
+            panic!("Variant index {} is out-of-bounds for type {} with {} variants", index, T::type_name(), n_variants);
+        }
+        let (word, mask): (usize, u64) = (index / 64, 1 << (index % 64));
+        let new: bool = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        new
+    }
+
+    /// Adds the variant with the given name to the set.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant to add.
+    ///
+    /// # Returns
+    /// True if the variant was newly added, or false if it was already in the set.
+    ///
+    /// # Errors
+    /// This function errors if `name` is not the name of any variant of `T`.
+    #[inline]
+    pub fn insert_name(&mut self, name: &str) -> Result<bool, UnknownVariantError> {
+        match T::variant_index_of(name) {
+            Some(index) => Ok(self.insert_index(index)),
+            None => Err(UnknownVariantError::new::<T>(name)),
+        }
+    }
+
+    /// Removes the variant of the given value from the set.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to remove the variant.
+    ///
+    /// # Returns
+    /// True if the variant was in the set, or false otherwise.
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool { self.remove_index(value.variant_index()) }
+
+    /// Removes the variant with the given index from the set.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// True if the variant was in the set, or false otherwise (including when `index` is
+    /// out-of-bounds).
+    #[inline]
+    pub fn remove_index(&mut self, index: usize) -> bool {
+        let present: bool = self.contains_index(index);
+        if present {
+            self.bits[index / 64] &= !(1 << (index % 64));
+        }
+        present
+    }
+
+    /// Removes all variants from the set.
+    #[inline]
+    pub fn clear(&mut self) { self.bits.iter_mut().for_each(|w| *w = 0); }
+
+
+
+    /// Checks whether the variant of the given value is in the set.
+    ///
+    /// # Arguments
+    /// - `value`: The value of which to check the variant.
+    ///
+    /// # Returns
+    /// True if the variant of `value` is in the set, or false otherwise.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool { self.contains_index(value.variant_index()) }
+
+    /// Checks whether the variant with the given index is in the set.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the variant in [`EnumDebug::variant_names()`].
+    ///
+    /// # Returns
+    /// True if the variant is in the set, or false otherwise (including when `index` is
+    /// out-of-bounds).
+    #[inline]
+    pub fn contains_index(&self, index: usize) -> bool {
+        self.bits.get(index / 64).map(|word| word & (1 << (index % 64)) != 0).unwrap_or(false)
+    }
+
+    /// Checks whether the variant with the given name is in the set.
+    ///
+    /// # Arguments
+    /// - `name`: The (unqualified) name of the variant to check.
+    ///
+    /// # Returns
+    /// True if the variant is in the set, or false otherwise (including when `name` is not a
+    /// variant of `T`).
+    #[inline]
+    pub fn contains_name(&self, name: &str) -> bool { T::variant_index_of(name).map(|index| self.contains_index(index)).unwrap_or(false) }
+
+    /// Returns the number of variants in the set.
+    #[inline]
+    pub fn len(&self) -> usize { self.bits.iter().map(|word| word.count_ones() as usize).sum() }
+
+    /// Returns whether there are no variants in the set.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.bits.iter().all(|word| *word == 0) }
+
+    /// Checks whether all variants in this set are also in another.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to check against.
+    ///
+    /// # Returns
+    /// True if `self` is a subset of `other`, or false otherwise.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool { self.bits.iter().zip(other.bits.iter()).all(|(lhs, rhs)| lhs & !rhs == 0) }
+
+
+
+    /// Computes the union of this set and another.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to combine with.
+    ///
+    /// # Returns
+    /// A new VariantSet with all variants that are in either `self` or `other`.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self { self.combine(other, |lhs, rhs| lhs | rhs) }
+
+    /// Computes the intersection of this set and another.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to combine with.
+    ///
+    /// # Returns
+    /// A new VariantSet with all variants that are in both `self` and `other`.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self { self.combine(other, |lhs, rhs| lhs & rhs) }
+
+    /// Computes the difference of this set and another.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to combine with.
+    ///
+    /// # Returns
+    /// A new VariantSet with all variants that are in `self` but not in `other`.
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self { self.combine(other, |lhs, rhs| lhs & !rhs) }
+
+    /// Computes the complement of this set.
+    ///
+    /// # Returns
+    /// A new VariantSet with all variants of `T` that are not in `self`.
+    #[inline]
+    pub fn complement(&self) -> Self { Self::all().difference(self) }
+
+    /// Combines this set with another word-by-word.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to combine with.
+    /// - `op`: The operation that combines a word of `self` with the matching word of `other`.
+    ///
+    /// # Returns
+    /// A new VariantSet with the combined words.
+    #[inline]
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        Self { bits: self.bits.iter().zip(other.bits.iter()).map(|(lhs, rhs)| op(*lhs, *rhs)).collect(), _t: PhantomData }
+    }
+
+
+
+    /// Returns an iterator over the names of the variants in this set.
+    ///
+    /// # Returns
+    /// An [`Iter`] that yields the names of the variants in the order they appear in
+    /// [`EnumDebug::variant_names()`].
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> { Iter { set: self, index: 0 } }
+
+    /// Returns an iterator over the indices of the variants in this set.
+    ///
+    /// # Returns
+    /// An iterator that yields indices into [`EnumDebug::variant_names()`] in ascending order.
+    #[inline]
+    pub fn indices(&self) -> impl '_ + Iterator<Item = usize> { (0..T::variant_names().len()).filter(|index| self.contains_index(*index)) }
+}
+
+impl<T> Clone for VariantSet<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self { bits: self.bits.clone(), _t: PhantomData } }
+}
+impl<T> Eq for VariantSet<T> {}
+impl<T> PartialEq for VariantSet<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.bits == other.bits }
+}
+impl<T> Hash for VariantSet<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.bits.hash(state) }
+}
+
+impl<T: EnumDebug> Debug for VariantSet<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_set().entries(self.iter().map(QualifiedName::<T>::new)).finish()
+    }
+}
+impl<T: EnumDebug> Display for VariantSet<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_set().entries(self.iter().map(UnqualifiedName)).finish() }
+}
+impl<T: EnumDebug> FromStr for VariantSet<T> {
+    type Err = UnknownVariantError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Strip the optional brackets
+        let mut s: &str = s.trim();
+        if let Some(inner) = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            s = inner;
+        }
+
+        // Parse the names one-by-one
+        let mut set: Self = Self::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            // Allow the name to be qualified with our own type name
+            let name: &str = name.strip_prefix(T::type_name()).and_then(|name| name.strip_prefix("::")).unwrap_or(name);
+            set.insert_name(name)?;
+        }
+        Ok(set)
+    }
+}
+
+impl<'a, T: EnumDebug> Extend<&'a T> for VariantSet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+impl<'a, T: EnumDebug> FromIterator<&'a T> for VariantSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        let mut set: Self = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+impl<'s, T: EnumDebug> IntoIterator for &'s VariantSet<T> {
+    type IntoIter = Iter<'s, T>;
+    type Item = &'static str;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
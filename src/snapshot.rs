@@ -0,0 +1,406 @@
+//  SNAPSHOT.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:07:24
+//  Last edited:
+//    19 Oct 2026, 00:38:05
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements snapshot tests that lock the variant names of [`EnumDebug`]
+//!   types.
+//!
+//!   When variant names are persisted or exchanged, they are a public
+//!   contract. [`assert_variants()`] records [`EnumDebug::type_name()`] and
+//!   [`EnumDebug::variant_names()`] to a file the first time it runs, and
+//!   panics with a diff in later runs if variants were removed, renamed or
+//!   reordered:
+//!   ```rust,no_run
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   #[test]
+//!   fn jedi_is_stable() { enum_debug::snapshot::assert_variants::<Jedi>("snapshots/jedi.txt"); }
+//!   ```
+//!
+//!   Relative paths are relative to the package being tested (i.e.,
+//!   `CARGO_MANIFEST_DIR`). Whether new variants are accepted is configured
+//!   with an [`AdditionPolicy`] (see [`Snapshot`]). To accept changes, run the
+//!   tests with the [`UPDATE_VAR`] environment variable set (e.g.,
+//!   `ENUM_DEBUG_UPDATE_SNAPSHOTS=1 cargo test`) and commit the updated
+//!   snapshots.
+//!
+//!   The snapshot file lists the type name and then every variant name on its
+//!   own line, with newlines and backslashes escaped:
+//!   ```plain
+//!   type Jedi
+//!   variant ObiWanKenobi
+//!   variant AnakinSkywalker
+//!   ```
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use crate::EnumDebug;
+
+
+/***** CONSTANTS *****/
+/// The environment variable that, when set to anything but empty or `0`, makes snapshot checks
+/// overwrite the snapshot with the current variants instead of failing.
+pub const UPDATE_VAR: &str = "ENUM_DEBUG_UPDATE_SNAPSHOTS";
+
+
+
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when checking a snapshot.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Failed to read an existing snapshot.
+    FileRead { path: PathBuf, err: io::Error },
+    /// Failed to create the directory of a snapshot.
+    DirCreate { path: PathBuf, err: io::Error },
+    /// Failed to write a snapshot.
+    FileWrite { path: PathBuf, err: io::Error },
+    /// An existing snapshot has a line that is not in the expected format.
+    IllegalLine { path: PathBuf, line: usize },
+    /// The variants do not match the snapshot.
+    Mismatch {
+        /// The path of the snapshot.
+        path:     PathBuf,
+        /// The lines in the snapshot.
+        snapshot: Vec<String>,
+        /// The lines that would be in the snapshot for the current variants.
+        actual:   Vec<String>,
+        /// Whether variants were removed, renamed or reordered (true), or only added (false).
+        breaking: bool,
+    },
+}
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use SnapshotError::*;
+        match self {
+            FileRead { path, .. } => write!(f, "Failed to read snapshot '{}'", path.display()),
+            DirCreate { path, .. } => write!(f, "Failed to create snapshot directory '{}'", path.display()),
+            FileWrite { path, .. } => write!(f, "Failed to write snapshot '{}'", path.display()),
+            IllegalLine { path, line } => write!(f, "Line {line} in snapshot '{}' is not a type or variant name", path.display()),
+            Mismatch { path, snapshot, actual, breaking } => {
+                if *breaking {
+                    writeln!(f, "Variants were removed, renamed or reordered compared to snapshot '{}':", path.display())?;
+                } else {
+                    writeln!(f, "Variants were added in a way not allowed by the policy compared to snapshot '{}':", path.display())?;
+                }
+                for (op, line) in diff(snapshot, actual) {
+                    writeln!(f, "{op} {line}")?;
+                }
+                write!(f, "Run with {UPDATE_VAR}=1 to accept the changes")
+            },
+        }
+    }
+}
+impl Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use SnapshotError::*;
+        match self {
+            FileRead { err, .. } | DirCreate { err, .. } | FileWrite { err, .. } => Some(err),
+            IllegalLine { .. } | Mismatch { .. } => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Escapes a name such that it fits on a single line.
+///
+/// # Arguments
+/// - `name`: The name to escape.
+///
+/// # Returns
+/// The name with backslashes, newlines and carriage returns escaped.
+fn escape(name: &str) -> String { name.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r") }
+
+/// Renders the lines of the snapshot of an [`EnumDebug`] type.
+///
+/// # Returns
+/// The lines, without newlines.
+fn render<T: ?Sized + EnumDebug>() -> Vec<String> {
+    let mut lines: Vec<String> = Vec::with_capacity(1 + T::variant_names().len());
+    lines.push(format!("type {}", escape(T::type_name())));
+    lines.extend(T::variants().map(|name| format!("variant {}", escape(name))));
+    lines
+}
+
+/// Computes a line diff between two lists of lines.
+///
+/// # Arguments
+/// - `old`: The old lines.
+/// - `new`: The new lines.
+///
+/// # Returns
+/// Every line of both lists, prefixed with `-` if it is only in `old`, `+` if it is only in `new`,
+/// or a space if it is in both.
+fn diff<'s>(old: &'s [String], new: &'s [String]) -> Vec<(char, &'s str)> {
+    // Compute the lengths of the longest common subsequences of all suffixes
+    let mut lcs: Vec<Vec<usize>> = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    // Walk it to find the edits
+    let mut res: Vec<(char, &str)> = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j): (usize, usize) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            res.push((' ', &old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            res.push(('+', &new[j]));
+            j += 1;
+        } else {
+            res.push(('-', &old[i]));
+            i += 1;
+        }
+    }
+    res
+}
+
+/// Checks whether one list is a subsequence of another.
+///
+/// # Arguments
+/// - `needle`: The list that should be a subsequence.
+/// - `haystack`: The list that should contain `needle`.
+///
+/// # Returns
+/// True if all elements of `needle` occur in `haystack` in the same order, or false otherwise.
+fn is_subsequence(needle: &[String], haystack: &[String]) -> bool {
+    let mut haystack = haystack.iter();
+    needle.iter().all(|elem| haystack.any(|other| other == elem))
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Determines whether variants may be added without failing a snapshot check.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AdditionPolicy {
+    /// Any added variant fails the check.
+    Deny,
+    /// Variants may only be added after the existing ones. Use this when the variant indices are
+    /// persisted too.
+    Append,
+    /// Variants may be added anywhere, as long as the existing ones keep their order.
+    #[default]
+    Anywhere,
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Checks that the variants of an [`EnumDebug`] type match a snapshot, and panics otherwise.
+///
+/// This uses the default [`AdditionPolicy`], which allows added variants. Use [`Snapshot`] to
+/// configure another policy.
+///
+/// See the [module-level documentation](self) for more information.
+///
+/// # Arguments
+/// - `path`: The path of the snapshot. Relative paths are relative to `CARGO_MANIFEST_DIR`.
+///
+/// # Panics
+/// This function panics with a diff if the variants no longer match the snapshot, or if the
+/// snapshot could not be read or written.
+#[inline]
+#[track_caller]
+pub fn assert_variants<T: ?Sized + EnumDebug>(path: impl Into<PathBuf>) { Snapshot::new(path).assert::<T>() }
+
+/// Checks whether snapshots should be updated instead of checked.
+///
+/// Use this to update other generated files under the same rule as snapshots.
+///
+/// # Returns
+/// True if [`UPDATE_VAR`] is set to anything but empty or `0`.
+#[inline]
+pub fn update_requested() -> bool { env::var_os(UPDATE_VAR).is_some_and(|value| !value.is_empty() && value != "0") }
+
+
+
+/// Configures a snapshot check for the variants of an [`EnumDebug`] type.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::snapshot::{AdditionPolicy, Snapshot, SnapshotError};
+/// use enum_debug::EnumDebug;
+///
+/// mod v1 {
+///     #[derive(enum_debug::EnumDebug)]
+///     pub enum Jedi {
+///         ObiWanKenobi,
+///         AnakinSkywalker,
+///     }
+/// }
+/// mod v2 {
+///     #[derive(enum_debug::EnumDebug)]
+///     pub enum Jedi {
+///         ObiWanKenobi,
+///         MaceWindu,
+///         AnakinSkywalker,
+///     }
+/// }
+/// mod v3 {
+///     #[derive(enum_debug::EnumDebug)]
+///     pub enum Jedi {
+///         AnakinSkywalker,
+///         ObiWanKenobi,
+///     }
+/// }
+///
+/// let path = std::env::temp_dir().join("enum-debug-snapshot-doctest/jedi.txt");
+/// # let _ = std::fs::remove_file(&path);
+/// // The first run records the snapshot...
+/// Snapshot::new(&path).check::<v1::Jedi>().unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "type Jedi\nvariant ObiWanKenobi\nvariant AnakinSkywalker\n");
+///
+/// // ...after which additions depend on the policy...
+/// Snapshot::new(&path).check::<v2::Jedi>().unwrap();
+/// assert!(Snapshot::new(&path).additions(AdditionPolicy::Append).check::<v2::Jedi>().is_err());
+///
+/// // ...but reorderings always fail
+/// let err = Snapshot::new(&path).check::<v3::Jedi>().unwrap_err();
+/// assert!(matches!(err, SnapshotError::Mismatch { breaking: true, .. }));
+/// assert_eq!(
+///     err.to_string(),
+///     format!(
+///         r#"Variants were removed, renamed or reordered compared to snapshot '{}':
+///   type Jedi
+/// + variant AnakinSkywalker
+///   variant ObiWanKenobi
+/// - variant AnakinSkywalker
+/// Run with ENUM_DEBUG_UPDATE_SNAPSHOTS=1 to accept the changes"#,
+///         path.display()
+///     )
+/// );
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// The path of the snapshot.
+    path:      PathBuf,
+    /// Whether variants may be added.
+    additions: AdditionPolicy,
+}
+impl Snapshot {
+    /// Constructor for the Snapshot.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the snapshot. Relative paths are relative to `CARGO_MANIFEST_DIR`.
+    ///
+    /// # Returns
+    /// A new Snapshot with the default [`AdditionPolicy`].
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self { Self { path: path.into(), additions: AdditionPolicy::default() } }
+
+    /// Sets whether variants may be added.
+    ///
+    /// # Arguments
+    /// - `policy`: The [`AdditionPolicy`] to use.
+    ///
+    /// # Returns
+    /// The same Snapshot, for chaining.
+    #[inline]
+    pub fn additions(mut self, policy: AdditionPolicy) -> Self {
+        self.additions = policy;
+        self
+    }
+
+
+
+    /// Checks that the variants of an [`EnumDebug`] type match the snapshot.
+    ///
+    /// If the snapshot does not exist yet, or if [`UPDATE_VAR`] is set, it is (over)written with
+    /// the current variants instead.
+    ///
+    /// # Errors
+    /// This function errors if the variants do not match the snapshot, or if the snapshot could
+    /// not be read or written.
+    pub fn check<T: ?Sized + EnumDebug>(&self) -> Result<(), SnapshotError> {
+        let path: PathBuf = match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) if self.path.is_relative() => Path::new(&dir).join(&self.path),
+            _ => self.path.clone(),
+        };
+        let actual: Vec<String> = render::<T>();
+        let update: bool = update_requested();
+
+        // Read the snapshot, if any
+        let contents: String = match fs::read_to_string(&path) {
+            Ok(contents) if !update => contents,
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(SnapshotError::FileRead { path, err }),
+            _ => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir).map_err(|err| SnapshotError::DirCreate { path: dir.into(), err })?;
+                }
+                let mut contents: String = actual.join("\n");
+                contents.push('\n');
+                return fs::write(&path, contents).map_err(|err| SnapshotError::FileWrite { path, err });
+            },
+        };
+        let mut snapshot: Vec<String> = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let prefix: &str = if snapshot.is_empty() { "type " } else { "variant " };
+            if !line.starts_with(prefix) {
+                return Err(SnapshotError::IllegalLine { path, line: i + 1 });
+            }
+            snapshot.push(line.into());
+        }
+
+        // Compare them
+        if snapshot == actual {
+            return Ok(());
+        }
+        let breaking: bool = snapshot.first() != actual.first() || !is_subsequence(&snapshot, &actual);
+        let allowed: bool = !breaking
+            && match self.additions {
+                AdditionPolicy::Deny => false,
+                AdditionPolicy::Append => actual.starts_with(&snapshot),
+                AdditionPolicy::Anywhere => true,
+            };
+        if allowed { Ok(()) } else { Err(SnapshotError::Mismatch { path, snapshot, actual, breaking }) }
+    }
+
+    /// Checks that the variants of an [`EnumDebug`] type match the snapshot, and panics otherwise.
+    ///
+    /// See [`Snapshot::check()`] for more information.
+    ///
+    /// # Panics
+    /// This function panics with a diff if the variants do not match the snapshot, or if the
+    /// snapshot could not be read or written.
+    #[track_caller]
+    pub fn assert<T: ?Sized + EnumDebug>(&self) {
+        if let Err(err) = self.check::<T>() {
+            panic!("{err}");
+        }
+    }
+}
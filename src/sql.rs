@@ -0,0 +1,68 @@
+//  SQL.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:54:57
+//  Last edited:
+//    18 Oct 2026, 23:59:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements helpers for storing [`EnumDebug`] types in SQL databases as
+//!   their variant names.
+//!
+//!   The conversions to and from database values themselves are implemented
+//!   by the `rusqlite`- and `sqlx`-modules (only available with the features
+//!   of the same name).
+//
+
+use std::fmt::Write as _;
+
+use crate::EnumDebug;
+
+
+/***** LIBRARY *****/
+/// Generates a `CHECK`-constraint that restricts a column to the variant names of an
+/// [`EnumDebug`] type.
+///
+/// Use this when creating the table in which the type is stored as TEXT, so that the database
+/// rejects names that would fail to decode.
+///
+/// # Arguments
+/// - `column`: The name of the column to constrain. It is inserted as-is, so quote it yourself if
+///   necessary.
+///
+/// # Returns
+/// A constraint of the form `CHECK (column IN ('A', 'B', ...))`. If the type has no variants, the
+/// constraint is `CHECK (FALSE)` instead, as no value is valid.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::sql::check_constraint;
+/// use enum_debug::EnumDebug;
+///
+/// #[derive(EnumDebug)]
+/// enum Jedi {
+///     ObiWanKenobi,
+///     AnakinSkywalker,
+/// }
+///
+/// assert_eq!(check_constraint::<Jedi>("master"), "CHECK (master IN ('ObiWanKenobi', 'AnakinSkywalker'))");
+/// ```
+pub fn check_constraint<T: ?Sized + EnumDebug>(column: &str) -> String {
+    let variants: &'static [&'static str] = T::variant_names();
+    if variants.is_empty() {
+        return String::from("CHECK (FALSE)");
+    }
+
+    let mut res: String = format!("CHECK ({column} IN (");
+    for (i, variant) in variants.iter().enumerate() {
+        if i > 0 {
+            res.push_str(", ");
+        }
+        write!(res, "'{}'", variant.replace('\'', "''")).unwrap();
+    }
+    res.push_str("))");
+    res
+}
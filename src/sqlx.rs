@@ -0,0 +1,145 @@
+//  SQLX.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:54:57
+//  Last edited:
+//    18 Oct 2026, 23:59:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `sqlx` for [`EnumDebug`] types.
+//!
+//!   Fieldless enums can be stored in text columns through the
+//!   [`EnumDebug`](derive@crate::EnumDebug)-derive macro by adding
+//!   `#[enum_debug(sqlx)]`. This implements `Type`, `Encode` and `Decode` for
+//!   every database that supports strings. Values are encoded as
+//!   [`EnumDebug::variant_name()`] and decoded with
+//!   [`FromVariant::from_variant_name()`]. Names that do not match any variant
+//!   fail to decode with an [`UnknownVariantError`](crate::UnknownVariantError) listing
+//!   [`EnumDebug::variant_names()`]:
+//!   ```rust
+//!   use enum_debug::sql::check_constraint;
+//!   use enum_debug::{EnumDebug, UnknownVariantError};
+//!   use sqlx::{Connection as _, SqliteConnection};
+//!
+//!   #[derive(Debug, EnumDebug, PartialEq)]
+//!   #[enum_debug(sqlx)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//!   let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+//!   let schema = format!("CREATE TABLE jedi (name TEXT NOT NULL, master TEXT {})", check_constraint::<Jedi>("master"));
+//!   sqlx::raw_sql(sqlx::AssertSqlSafe(schema)).execute(&mut conn).await.unwrap();
+//!   sqlx::query("INSERT INTO jedi VALUES ('Anakin', ?)").bind(Jedi::ObiWanKenobi).execute(&mut conn).await.unwrap();
+//!
+//!   let master: Jedi = sqlx::query_scalar("SELECT master FROM jedi WHERE name = 'Anakin'").fetch_one(&mut conn).await.unwrap();
+//!   assert_eq!(master, Jedi::ObiWanKenobi);
+//!   let raw: String = sqlx::query_scalar("SELECT master FROM jedi WHERE name = 'Anakin'").fetch_one(&mut conn).await.unwrap();
+//!   assert_eq!(raw, "ObiWanKenobi");
+//!
+//!   // The constraint rejects unknown names...
+//!   assert!(sqlx::query("INSERT INTO jedi VALUES ('Luke', 'DarthVader')").execute(&mut conn).await.is_err());
+//!   // ...but if they end up in the database anyway, they fail to decode
+//!   let err = sqlx::query_scalar::<_, Jedi>("SELECT 'DarthVader'").fetch_one(&mut conn).await.unwrap_err();
+//!   let sqlx::Error::ColumnDecode { source, .. } = err else { panic!("Unexpected error: {err}") };
+//!   assert_eq!(source.downcast_ref::<UnknownVariantError>(), Some(&UnknownVariantError::new::<Jedi>("DarthVader")));
+//!   assert_eq!(source.to_string(), "Unknown variant 'DarthVader' for type Jedi (expected one of: 'ObiWanKenobi', 'AnakinSkywalker')");
+//!   # });
+//!   ```
+//!
+//!   This module is only available when the `sqlx`-feature is used.
+//
+
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+use crate::{EnumDebug, FromVariant};
+
+
+/***** LIBRARY *****/
+/// Returns the type information of the column an [`EnumDebug`] type is stored in.
+///
+/// Used by the implementation derived with `#[enum_debug(sqlx)]`.
+///
+/// # Returns
+/// The type information of [`str`] in the given database.
+#[inline]
+pub fn type_info<DB: Database>() -> DB::TypeInfo
+where
+    str: Type<DB>,
+{
+    <str as Type<DB>>::type_info()
+}
+
+/// Checks whether an [`EnumDebug`] type can be decoded from a column of the given type.
+///
+/// Used by the implementation derived with `#[enum_debug(sqlx)]`.
+///
+/// # Arguments
+/// - `ty`: The type information of the column.
+///
+/// # Returns
+/// True if a [`str`] can be decoded from the column, or false otherwise.
+#[inline]
+pub fn compatible<DB: Database>(ty: &DB::TypeInfo) -> bool
+where
+    str: Type<DB>,
+{
+    <str as Type<DB>>::compatible(ty)
+}
+
+/// Encodes a variant of an [`EnumDebug`] type as its variant name.
+///
+/// Used by the implementation derived with `#[enum_debug(sqlx)]`.
+///
+/// # Arguments
+/// - `value`: The value to encode.
+/// - `buf`: The buffer to encode to.
+///
+/// # Returns
+/// Whether the encoded value is `NULL` (which it never is).
+///
+/// # Errors
+/// This function errors if the database failed to encode the [`EnumDebug::variant_name()`].
+#[inline]
+pub fn encode<'q, DB: Database, T: ?Sized + EnumDebug>(value: &T, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError>
+where
+    &'static str: Encode<'q, DB>,
+{
+    <&'static str as Encode<'q, DB>>::encode(value.variant_name(), buf)
+}
+
+/// Decodes a variant of an [`EnumDebug`] type from its variant name.
+///
+/// Used by the implementation derived with `#[enum_debug(sqlx)]`.
+///
+/// # Arguments
+/// - `value`: The raw database value to decode.
+///
+/// # Returns
+/// The variant whose name matches the given value.
+///
+/// # Errors
+/// This function errors if the value is not a string, or with an [`UnknownVariantError`](crate::UnknownVariantError) if it
+/// does not match any variant.
+pub fn decode<'r, DB: Database, T: FromVariant>(value: DB::ValueRef<'r>) -> Result<T, BoxDynError>
+where
+    &'r str: Decode<'r, DB>,
+{
+    let name: &str = <&'r str as Decode<'r, DB>>::decode(value)?;
+    Ok(T::from_variant_name(name)?)
+}
+
+
+
+/// Re-exports used by the implementation derived with `#[enum_debug(sqlx)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::sqlx;
+}
@@ -0,0 +1,174 @@
+//  TRACING.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:28:57
+//  Last edited:
+//    19 Oct 2026, 01:25:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements integration with `tracing` for [`EnumDebug`] types.
+//!
+//!   Because [`EnumDebug::variant_name()`] returns a `&'static str`, it can
+//!   be recorded as a structured field without allocating:
+//!   ```rust
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Message {
+//!       Ping,
+//!       Data(Vec<u8>),
+//!   }
+//!
+//!   let msg = Message::Data(vec![42]);
+//!   tracing::info!(kind = msg.variant_name(), qualified_kind = enum_debug::tracing::qualified(&msg), "Received message");
+//!   ```
+//!
+//!   Note that [`tracing::Value`](::tracing::Value) is sealed, i.e., it can
+//!   only be implemented by `tracing` itself. Neither [`EnumDebugFormatter`]
+//!   nor enums can therefore be recorded directly; record
+//!   [`variant_name()`](EnumDebug::variant_name()) instead of the value, and
+//!   [`qualified()`] instead of a [qualified](EnumDebugFormatter::qualified())
+//!   formatter.
+//!
+//!   To add both the variant and the type of a value to a span, use
+//!   [`variant_span!`](crate::variant_span!) or [`record_variant()`].
+//!
+//!   This module is only available when the `tracing`-feature is used.
+//
+
+use ::tracing::field::{display, DisplayValue};
+use ::tracing::Span;
+
+use crate::{EnumDebug, EnumDebugFormatter};
+
+
+/***** LIBRARY *****/
+/// Creates a new [`Span`] with fields describing the variant of an [`EnumDebug`] value.
+///
+/// The span gets a `variant`-field with the [variant name](EnumDebug::variant_name()) and an
+/// `enum_type`-field with the [type name](EnumDebug::type_name()) of the value. Both are recorded
+/// as `&'static str`s. Any other fields can be given after the value, using the same syntax as
+/// [`tracing::span!`](::tracing::span!).
+///
+/// This macro is only available when the `tracing`-feature is used.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::{variant_span, EnumDebug};
+/// use tracing::Level;
+/// # use std::fmt::Debug;
+/// # use std::sync::Mutex;
+/// # use tracing::field::{Field, Visit};
+/// # use tracing::span::{Attributes, Id, Record};
+/// # use tracing::{Event, Metadata, Subscriber};
+/// #
+/// # // A subscriber that remembers the fields of new spans
+/// # static FIELDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+/// # struct Fields;
+/// # impl Visit for Fields {
+/// #     fn record_debug(&mut self, field: &Field, value: &dyn Debug) { FIELDS.lock().unwrap().push(format!("{field}={value:?}")); }
+/// #     fn record_str(&mut self, field: &Field, value: &str) { FIELDS.lock().unwrap().push(format!("{field}={value}")); }
+/// # }
+/// # struct Collector;
+/// # impl Subscriber for Collector {
+/// #     fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+/// #     fn new_span(&self, span: &Attributes<'_>) -> Id { span.record(&mut Fields); Id::from_u64(1) }
+/// #     fn record(&self, _: &Id, _: &Record<'_>) {}
+/// #     fn record_follows_from(&self, _: &Id, _: &Id) {}
+/// #     fn event(&self, _: &Event<'_>) {}
+/// #     fn enter(&self, _: &Id) {}
+/// #     fn exit(&self, _: &Id) {}
+/// # }
+/// # tracing::subscriber::set_global_default(Collector).unwrap();
+///
+/// #[derive(EnumDebug)]
+/// enum Job {
+///     Build { target: String },
+///     Test,
+/// }
+///
+/// let job = Job::Build { target: "release".into() };
+/// let span = variant_span!(Level::INFO, "job", job, attempt = 1);
+/// let _guard = span.enter();
+/// assert_eq!(*FIELDS.lock().unwrap(), vec!["variant=Build", "enum_type=Job", "attempt=1"]);
+/// ```
+#[macro_export]
+macro_rules! variant_span {
+    ($lvl:expr, $name:expr, $value:expr) => {
+        $crate::variant_span!($lvl, $name, $value,)
+    };
+    ($lvl:expr, $name:expr, $value:expr, $($fields:tt)*) => {{
+        let value = &$value;
+        $crate::tracing::__private::tracing::span!(
+            $lvl,
+            $name,
+            variant = $crate::EnumDebug::variant_name(value),
+            enum_type = $crate::tracing::type_name_of(value),
+            $($fields)*
+        )
+    }};
+}
+
+
+
+/// Returns the [type name](EnumDebug::type_name()) of the given value.
+///
+/// # Arguments
+/// - `value`: The value of which to get the type name.
+///
+/// # Returns
+/// A `&'static str` with the name of the value's type.
+#[inline]
+pub fn type_name_of<T: ?Sized + EnumDebug>(_value: &T) -> &'static str { T::type_name() }
+
+/// Returns the qualified variant name of the given value as a `tracing` value.
+///
+/// # Arguments
+/// - `value`: The value of which to record the qualified variant name.
+///
+/// # Returns
+/// A [`DisplayValue`] that records `Type::Variant` without allocating.
+#[inline]
+pub fn qualified<T: EnumDebug>(value: &T) -> DisplayValue<EnumDebugFormatter<'_, T>> { display(value.variant().qualified()) }
+
+/// Records the variant of an [`EnumDebug`] value in an existing [`Span`].
+///
+/// The span must have declared (possibly [`Empty`](::tracing::field::Empty)) `variant`- and
+/// `enum_type`-fields when it was created. These are set to the
+/// [variant name](EnumDebug::variant_name()) and [type name](EnumDebug::type_name()) of the value,
+/// respectively.
+///
+/// # Arguments
+/// - `span`: The [`Span`] to record in.
+/// - `value`: The value of which to record the variant.
+///
+/// # Example
+/// ```rust
+/// use enum_debug::EnumDebug;
+/// use tracing::field::Empty;
+///
+/// #[derive(EnumDebug)]
+/// enum Job {
+///     Build,
+///     Test,
+/// }
+///
+/// let span = tracing::info_span!("job", variant = Empty, enum_type = Empty);
+/// enum_debug::tracing::record_variant(&span, &Job::Test);
+/// ```
+#[inline]
+pub fn record_variant<T: ?Sized + EnumDebug>(span: &Span, value: &T) {
+    span.record("variant", value.variant_name());
+    span.record("enum_type", T::type_name());
+}
+
+
+
+/// Re-exports used by the [`variant_span!`](crate::variant_span!)-macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use ::tracing;
+}
@@ -0,0 +1,261 @@
+//  TYPESCRIPT.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:43:22
+//  Last edited:
+//    19 Oct 2026, 01:45:49
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a generator for TypeScript types that mirror the variant
+//!   names of [`EnumDebug`] types.
+//!
+//!   Use it from a test or a `build.rs` script to keep a frontend in sync
+//!   with the Rust enums it exchanges by name:
+//!   ```rust
+//!   use enum_debug::typescript::TypeScriptGenerator;
+//!   use enum_debug::EnumDebug;
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Jedi {
+//!       ObiWanKenobi,
+//!       AnakinSkywalker,
+//!   }
+//!
+//!   #[derive(EnumDebug)]
+//!   enum Status {
+//!       Active { since: u64 },
+//!       Retired,
+//!   }
+//!
+//!   let generator = TypeScriptGenerator::new().add::<Jedi>().add::<Status>();
+//!   assert_eq!(
+//!       generator.generate().unwrap(),
+//!       r#"// Generated by enum-debug. Do not edit this file manually.
+//!
+//!   export type Jedi = "ObiWanKenobi" | "AnakinSkywalker";
+//!   export const JEDI_VARIANTS = ["ObiWanKenobi", "AnakinSkywalker"] as const;
+//!
+//!   export type Status = "Active" | "Retired";
+//!   export const STATUS_VARIANTS = ["Active", "Retired"] as const;
+//!   "#
+//!   );
+//!
+//!   // Write the file once, e.g., in `build.rs`...
+//!   let path = std::env::temp_dir().join("enum-debug-typescript-doctest.ts");
+//!   generator.write(&path).unwrap();
+//!   // ...and verify it in CI, e.g., in a test
+//!   generator.check(&path).unwrap();
+//!   assert!(TypeScriptGenerator::new().add::<Jedi>().check(&path).is_err());
+//!   # std::fs::remove_file(&path).unwrap();
+//!   ```
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::path::Path;
+
+use crate::generated::{check_generated, is_identifier, write_generated, GeneratedFileError, HEADER};
+use crate::{screaming_snake_case, EnumDebug};
+
+
+/***** ERRORS *****/
+/// Describes what went wrong when generating TypeScript types.
+#[derive(Debug)]
+pub enum TypeScriptError {
+    /// The type name of an [`EnumDebug`] type is not a valid TypeScript identifier.
+    IllegalTypeName { name: &'static str },
+    /// Two types have the same type name.
+    DuplicateTypeName { name: &'static str },
+    /// Two types map to the same name of the constant with their variant names.
+    DuplicateConstant { name: &'static str, constant: String },
+    /// Failed to write the generated file, or to check an existing one.
+    File { err: GeneratedFileError },
+}
+impl Display for TypeScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use TypeScriptError::*;
+        match self {
+            IllegalTypeName { name } => write!(f, "Type name '{name}' is not a valid TypeScript identifier"),
+            DuplicateTypeName { name } => write!(f, "Type name '{name}' is given more than once"),
+            DuplicateConstant { name, constant } => write!(f, "Constant '{constant}' of type {name} occurs more than once"),
+            File { err } => write!(f, "{err}"),
+        }
+    }
+}
+impl Error for TypeScriptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use TypeScriptError::*;
+        match self {
+            File { err } => err.source(),
+            _ => None,
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes a string as a TypeScript string literal.
+///
+/// # Arguments
+/// - `out`: The [`String`] to write to.
+/// - `value`: The string to write.
+fn write_literal(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{{{:x}}}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Generates a TypeScript file that mirrors the variant names of [`EnumDebug`] types.
+///
+/// For every type, the file contains a string union type named after [`EnumDebug::type_name()`],
+/// and a constant array with all [`EnumDebug::variant_names()`] in declaration order. The constant
+/// is named after the type in SCREAMING_SNAKE_CASE, followed by `_VARIANTS`.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Clone, Debug, Default)]
+pub struct TypeScriptGenerator {
+    /// The type names and variant names of the types to generate.
+    types: Vec<(&'static str, &'static [&'static str])>,
+}
+impl TypeScriptGenerator {
+    /// Constructor for the TypeScriptGenerator.
+    ///
+    /// # Returns
+    /// A new TypeScriptGenerator without any types.
+    #[inline]
+    pub fn new() -> Self { Self { types: Vec::new() } }
+
+    /// Adds an [`EnumDebug`] type to generate.
+    ///
+    /// Types are generated in the order in which they are added.
+    ///
+    /// # Returns
+    /// The same TypeScriptGenerator, for chaining.
+    #[inline]
+    pub fn add<T: ?Sized + EnumDebug>(mut self) -> Self {
+        self.types.push((T::type_name(), T::variant_names()));
+        self
+    }
+
+
+
+    /// Generates the TypeScript file.
+    ///
+    /// # Returns
+    /// The contents of the file.
+    ///
+    /// # Errors
+    /// This function errors if the type name of a type is not a valid TypeScript identifier (e.g.,
+    /// because it is a path), or if two types have the same type name or the same name for their
+    /// constant.
+    ///
+    /// # Example
+    /// ```rust
+    /// use enum_debug::typescript::TypeScriptGenerator;
+    /// use enum_debug::EnumDebug;
+    ///
+    /// #[derive(EnumDebug)]
+    /// enum HttpServer {
+    ///     Running,
+    /// }
+    ///
+    /// #[derive(EnumDebug)]
+    /// #[enum_debug(name = "HTTPServer")]
+    /// enum LegacyServer {
+    ///     Running,
+    /// }
+    ///
+    /// // Both would be exported as `HTTP_SERVER_VARIANTS`
+    /// assert!(TypeScriptGenerator::new().add::<HttpServer>().add::<LegacyServer>().generate().is_err());
+    /// ```
+    pub fn generate(&self) -> Result<String, TypeScriptError> {
+        let mut res: String = format!("// {HEADER}\n");
+        let mut constants: Vec<String> = Vec::with_capacity(self.types.len());
+        for (i, (name, variants)) in self.types.iter().enumerate() {
+            if !is_identifier(name, &['_', '$'], &['_', '$']) {
+                return Err(TypeScriptError::IllegalTypeName { name });
+            }
+            if self.types[..i].iter().any(|(other, _)| other == name) {
+                return Err(TypeScriptError::DuplicateTypeName { name });
+            }
+            let constant: String = format!("{}_VARIANTS", screaming_snake_case(name));
+            if constants.contains(&constant) {
+                return Err(TypeScriptError::DuplicateConstant { name, constant });
+            }
+
+            // Write the union type
+            write!(res, "\nexport type {name} = ").unwrap();
+            if variants.is_empty() {
+                res.push_str("never");
+            }
+            for (j, variant) in variants.iter().enumerate() {
+                if j > 0 {
+                    res.push_str(" | ");
+                }
+                write_literal(&mut res, variant);
+            }
+            res.push_str(";\n");
+
+            // Write the list of names
+            write!(res, "export const {constant} = [").unwrap();
+            for (j, variant) in variants.iter().enumerate() {
+                if j > 0 {
+                    res.push_str(", ");
+                }
+                write_literal(&mut res, variant);
+            }
+            res.push_str("] as const;\n");
+            constants.push(constant);
+        }
+        Ok(res)
+    }
+
+    /// Generates the TypeScript file and writes it to the given path.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to write. Any existing file is overwritten.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](TypeScriptGenerator::generate()) the file
+    /// or to write it.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), TypeScriptError> {
+        let contents: String = self.generate()?;
+        write_generated(path.as_ref(), &contents).map_err(|err| TypeScriptError::File { err })
+    }
+
+    /// Checks that the file at the given path is up-to-date.
+    ///
+    /// Use this in a test to fail when the committed file no longer matches the Rust types.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to check.
+    ///
+    /// # Errors
+    /// This function errors if we failed to [generate](TypeScriptGenerator::generate()) the file,
+    /// to read the existing file, or if its contents differ from what would be generated.
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), TypeScriptError> {
+        let contents: String = self.generate()?;
+        check_generated(path.as_ref(), &contents).map_err(|err| TypeScriptError::File { err })
+    }
+}
@@ -0,0 +1,158 @@
+//  COMPLETION.rs
+//    by Lut99
+//
+//  Created:
+//    19 Oct 2026, 00:25:13
+//  Last edited:
+//    19 Oct 2026, 01:23:17
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks the completion scripts generated by the `completion`-module
+//!   against the snapshots in `tests/completions`, and runs the bash script
+//!   through the local bash.
+//!
+//!   Run with `ENUM_DEBUG_UPDATE_SNAPSHOTS=1` to update the snapshots.
+//
+
+#![cfg(feature = "derive")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use enum_debug::completion::{CompletionGenerator, Shell};
+use enum_debug::snapshot::{update_requested, UPDATE_VAR};
+use enum_debug::EnumDebug;
+use serde::Serialize;
+
+
+/***** TYPES *****/
+/// The ranks to complete.
+#[allow(dead_code)]
+#[derive(EnumDebug, Serialize)]
+#[enum_debug(follow_serde)]
+enum Rank {
+    /// Still learning.
+    Padawan,
+    /// Done learning: "knighted".
+    Knight,
+    Master,
+    #[serde(rename = "Darth's Apprentice")]
+    DarthsApprentice,
+    /// Not for the faint of heart.
+    #[enum_debug(hidden)]
+    Sith,
+}
+
+/// The modes to complete.
+#[allow(dead_code)]
+#[derive(EnumDebug)]
+enum Mode {
+    Fast,
+    Full,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Returns the generator for the scripts under test.
+fn generator() -> CompletionGenerator { CompletionGenerator::new("jedi").bind::<Rank>(["-r", "--rank"]).bind::<Mode>(["--mode", "-mode"]) }
+
+/// Returns the path of the snapshot of a script.
+///
+/// # Arguments
+/// - `ext`: The extension of the snapshot, i.e., the name of the shell.
+fn snapshot(ext: &str) -> PathBuf { Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("completions").join(format!("jedi.{ext}")) }
+
+/// Checks the script for a shell against its snapshot, or updates it if asked.
+///
+/// # Arguments
+/// - `shell`: The shell to check the script of.
+/// - `ext`: The extension of the snapshot.
+fn check(shell: Shell, ext: &str) {
+    let path: PathBuf = snapshot(ext);
+    if update_requested() {
+        generator().write(shell, &path).unwrap();
+    } else if let Err(err) = generator().check(shell, &path) {
+        panic!("{err}\n\nGenerated:\n{}\nRun with {UPDATE_VAR}=1 to accept the changes", generator().generate(shell).unwrap());
+    }
+}
+
+/// Runs the bash script to complete a command line.
+///
+/// # Arguments
+/// - `words`: The words on the command line, including the one being completed.
+///
+/// # Returns
+/// The completions, one per line.
+fn complete_bash(words: &[&str]) -> String {
+    let script: String = generator().generate(Shell::Bash).unwrap();
+    let words: Vec<String> = words.iter().map(|word| format!("'{}'", word.replace('\'', "'\\''"))).collect();
+    let output: Output = Command::new("bash")
+        .arg("--norc")
+        .arg("-c")
+        .arg(format!(
+            "{script}\nCOMP_WORDS=({})\nCOMP_CWORD={}\n_jedi\nprintf '%s\\n' \"${{COMPREPLY[@]}}\"",
+            words.join(" "),
+            words.len() - 1
+        ))
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to run bash: {err}"));
+    assert!(output.status.success(), "bash failed:\n{}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stderr.is_empty(), "bash complained:\n{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+
+
+
+
+/***** TESTS *****/
+#[test]
+fn snapshot_bash() { check(Shell::Bash, "bash"); }
+
+#[test]
+fn snapshot_zsh() { check(Shell::Zsh, "zsh"); }
+
+#[test]
+fn snapshot_fish() { check(Shell::Fish, "fish"); }
+
+#[cfg(unix)]
+#[test]
+fn run_bash() {
+    // Hidden variants are left out, and names are escaped as bash inserts them verbatim
+    assert_eq!(complete_bash(&["jedi", "--rank", ""]), "Padawan\nKnight\nMaster\nDarth\\'s\\ Apprentice\n");
+    assert_eq!(complete_bash(&["jedi", "-r", "D"]), "Darth\\'s\\ Apprentice\n");
+    assert_eq!(complete_bash(&["jedi", "-r", "Darth\\'s\\ A"]), "Darth\\'s\\ Apprentice\n");
+    assert_eq!(complete_bash(&["jedi", "-r", "S"]), "\n");
+    assert_eq!(complete_bash(&["jedi", "--rank", "=", "M"]), "Master\n");
+    assert_eq!(complete_bash(&["jedi", "--rank", "="]), "Padawan\nKnight\nMaster\nDarth\\'s\\ Apprentice\n");
+    assert_eq!(complete_bash(&["jedi", "-mode", "F"]), "Fast\nFull\n");
+    // Other arguments are left to bash
+    assert_eq!(complete_bash(&["jedi", "--other", "F"]), "\n");
+}
+
+#[test]
+fn references() {
+    // References complete the same variants, leaving out the same hidden ones
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        assert_eq!(
+            CompletionGenerator::new("jedi").bind::<&Rank>(["-r"]).generate(shell).unwrap(),
+            CompletionGenerator::new("jedi").bind::<Rank>(["-r"]).generate(shell).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn illegal() {
+    assert!(CompletionGenerator::new("jedi council").generate(Shell::Bash).is_err());
+    assert!(CompletionGenerator::new("jedi").bind::<Mode>(["mode"]).generate(Shell::Bash).is_err());
+    assert!(CompletionGenerator::new("jedi").bind::<Mode>(["---mode"]).generate(Shell::Bash).is_err());
+    assert!(CompletionGenerator::new("jedi").bind::<Mode>(["--mode"]).bind::<Rank>(["--mode"]).generate(Shell::Bash).is_err());
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        assert!(CompletionGenerator::new("jedi").bind::<Mode>([] as [&str; 0]).generate(shell).is_err());
+    }
+}
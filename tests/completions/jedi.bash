@@ -0,0 +1,35 @@
+# Generated by enum-debug. Do not edit this file manually.
+
+_jedi() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" prev="${COMP_WORDS[COMP_CWORD-1]}" name
+    # Complete `--flag=value` too
+    if [[ "$prev" == "=" && $COMP_CWORD -ge 2 ]]; then
+        prev="${COMP_WORDS[COMP_CWORD-2]}"
+    elif [[ "$cur" == "=" ]]; then
+        cur=""
+    fi
+    COMPREPLY=()
+    case "$prev" in
+        -r|--rank)
+            local names=('Padawan' 'Knight' 'Master' 'Darth'\''s Apprentice')
+            for name in "${names[@]}"; do
+                # Bash inserts completions verbatim, so escape them
+                printf -v name '%q' "$name"
+                [[ "$name" == "$cur"* ]] && COMPREPLY+=("$name")
+            done
+            return 0
+            ;;
+        --mode|-mode)
+            local names=('Fast' 'Full')
+            for name in "${names[@]}"; do
+                # Bash inserts completions verbatim, so escape them
+                printf -v name '%q' "$name"
+                [[ "$name" == "$cur"* ]] && COMPREPLY+=("$name")
+            done
+            return 0
+            ;;
+    esac
+    return 0
+}
+
+complete -o default -F _jedi jedi
@@ -0,0 +1,8 @@
+# Generated by enum-debug. Do not edit this file manually.
+
+complete -c jedi -s r -l rank -x -a 'Padawan' -d 'Still learning.'
+complete -c jedi -s r -l rank -x -a 'Knight' -d 'Done learning: "knighted".'
+complete -c jedi -s r -l rank -x -a 'Master'
+complete -c jedi -s r -l rank -x -a 'Darth\\\'s\\ Apprentice'
+complete -c jedi -l mode -o mode -x -a 'Fast'
+complete -c jedi -l mode -o mode -x -a 'Full'
@@ -0,0 +1,111 @@
+//  FFI.rs
+//    by Lut99
+//
+//  Created:
+//    18 Oct 2026, 23:48:02
+//  Last edited:
+//    19 Oct 2026, 01:01:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Compiles and runs a C program against the names exported by the `ffi`
+//!   example and the header generated for it.
+//!
+//!   The example is built as a static library by the test itself, in the
+//!   same profile as the test. The C program is compiled with the system C
+//!   compiler (`cc`, or whatever is in the `CC` environment variable).
+//
+
+#![cfg(all(target_os = "linux", feature = "derive", feature = "ffi"))]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::{env, fs};
+
+use enum_debug::ffi::CHeaderGenerator;
+
+#[path = "../examples/ffi.rs"]
+mod example;
+
+
+/***** CONSTANTS *****/
+/// The C program to compile.
+const PROGRAM: &str = r#"#include <stdio.h>
+#include "jedi.h"
+
+int main(void) {
+    size_t count = jedi_variant_count();
+    printf("%zu\n", count);
+    for (size_t i = 0; i < count; i++) {
+        printf("%s\n", jedi_variant_name(i));
+    }
+    printf("%s\n", jedi_variant_name(JEDI_MACE_WINDU));
+    return jedi_variant_name(count) == NULL ? 0 : 1;
+}
+"#;
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds the `ffi` example as a static library.
+///
+/// The library is built in the same target directory and profile as this test.
+///
+/// # Returns
+/// The path of the built library.
+fn build_lib() -> PathBuf {
+    // Tests live in `<target>/<profile>/deps`
+    let exe: PathBuf = env::current_exe().unwrap();
+    let profile_dir: &Path = exe.parent().and_then(Path::parent).unwrap();
+    let target_dir: &Path = profile_dir.parent().unwrap();
+    let profile: &str = match profile_dir.file_name().and_then(|name| name.to_str()).unwrap() {
+        "debug" => "dev",
+        profile => profile,
+    };
+
+    let cargo: &str = env!("CARGO");
+    let output: Output = Command::new(cargo)
+        .args(["build", "--example", "ffi", "--features", "derive,ffi", "--profile", profile, "--target-dir"])
+        .arg(target_dir)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to run '{cargo}': {err}"));
+    assert!(output.status.success(), "Failed to build the `ffi` example:\n{}", String::from_utf8_lossy(&output.stderr));
+    profile_dir.join("examples").join("libffi.a")
+}
+
+
+
+
+
+/***** TESTS *****/
+#[test]
+fn c_program() {
+    let lib: PathBuf = build_lib();
+
+    // Generate the header and the program
+    let dir: PathBuf = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+    fs::create_dir_all(&dir).unwrap();
+    CHeaderGenerator::new("JEDI_H").add::<example::Jedi>().write(dir.join("jedi.h")).unwrap();
+    fs::write(dir.join("main.c"), PROGRAM).unwrap();
+
+    // Compile it...
+    let cc: String = env::var("CC").unwrap_or_else(|_| "cc".into());
+    let output: Output = Command::new(&cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-o"])
+        .arg(dir.join("main"))
+        .arg(dir.join("main.c"))
+        .arg(&lib)
+        .args(["-lgcc_s", "-lutil", "-lrt", "-lpthread", "-lm", "-ldl", "-lc"])
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to run C compiler '{cc}': {err}"));
+    assert!(output.status.success(), "Failed to compile C program:\n{}", String::from_utf8_lossy(&output.stderr));
+
+    // ...and run it
+    let output: Output = Command::new(dir.join("main")).output().unwrap();
+    assert!(output.status.success(), "C program failed:\n{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "4\nObiWanKenobi\nAnakinSkywalker\nMaceWindu\nMasterYoda\nMaceWindu\n");
+}